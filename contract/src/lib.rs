@@ -1,9 +1,34 @@
 //! Voting System Contract for Dusk Network
 //!
 //! Features:
-//! - Admin-managed proposals (only admin can create/close proposals)
+//! - Role-based access control, with roles granted by an admin
+//! - Committee admin whose operations run once M of N member keys approve them
+//! - Emergency pause that halts voting, proposal creation and execution
+//! - Versioned state that a successor deployment can import, freezing this one
+//! - Explicit proposal lifecycle with enforced state transitions
+//! - Block-height voting windows that open and close automatically
 //! - stDUSK token holders can vote (balance queried from stDUSK contract)
+//! - Allowlisted contracts (treasuries, vaults, multisigs) vote with their own balance
 //! - Weighted voting (1 stDUSK = 1 vote)
+//! - Per-proposal voting power strategies: token balance, stake, several tokens, a
+//!   registry of accounts, optionally square-rooted
+//! - Voting power read at a per-proposal snapshot height
+//! - Quorum and approval threshold evaluated when a proposal is closed
+//! - Yes / No / Abstain / NoWithVeto ballots with a configurable veto share
+//! - Multi-choice proposals with per-option weighted tallies
+//! - Vote changing and retraction while voting is open
+//! - Quadratic voting rounds: n votes on a proposal cost n² of a voter's stDUSK credits
+//! - Council elections on ranked ballots, counted by instant-runoff or weighted STV
+//! - Transitive vote delegation, overridden by a delegator's direct vote
+//! - Delegation scoped to a proposal category or a single proposal
+//! - Typed error codes returned to callers instead of traps
+//! - Events emitted for every state change
+//! - Paginated proposal queries filtered by status, creator or category
+//! - Archiving of old finalized proposals, keeping results and a vote commitment
+//! - Proposals indexed by ID for logarithmic lookups
+//! - Executable proposals that perform contract calls after a timelock
+//! - Timelock queue with a grace period, during which Cancellers can stop a proposal
+//! - Optional permissionless proposals gated by a stDUSK threshold and a DUSK deposit
 //! - Double-vote prevention
 //! - Secure sender verification via call stack
 
@@ -11,10 +36,11 @@
 
 extern crate alloc;
 
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::string::String;
 use alloc::vec::Vec;
 
+#[cfg(target_family = "wasm")]
 use dusk_core::abi;
 use dusk_core::abi::ContractId;
 use dusk_core::signatures::bls::PublicKey;
@@ -24,23 +50,238 @@ use bytecheck::CheckBytes;
 
 /// Maximum length for proposal descriptions
 const MAX_PROPOSAL_DESC_LEN: usize = 256;
-/// Maximum number of proposals
+/// Maximum number of live (not yet finalized) proposals
 const MAX_PROPOSALS: usize = 100;
+/// Maximum number of options on a multi-choice proposal
+const MAX_PROPOSAL_OPTIONS: usize = 10;
+/// Maximum length for a multi-choice option label
+const MAX_OPTION_LABEL_LEN: usize = 64;
+/// Maximum length for a proposal category tag
+const MAX_CATEGORY_LEN: usize = 32;
+/// Maximum number of proposals returned by one `get_proposals` call
+const MAX_PAGE_SIZE: usize = 50;
+/// Blocks after voting ends before a finalized proposal can be archived
+/// (about a week of 10-second blocks)
+const DEFAULT_ARCHIVE_AGE: u64 = 60_480;
+/// Maximum number of actions a proposal can carry
+const MAX_ACTIONS: usize = 10;
+/// Maximum length for an action's function name
+const MAX_FUNCTION_NAME_LEN: usize = 64;
+/// Maximum size of an action's encoded argument
+const MAX_ACTION_ARGS_LEN: usize = 1024;
+/// Minimum blocks between queueing a proposal and allowing execution
+/// (about a day of 10-second blocks)
+const DEFAULT_EXECUTION_DELAY: u64 = 8_640;
+/// Blocks after the delay during which a queued proposal can be executed
+/// (about a week of 10-second blocks)
+const DEFAULT_GRACE_PERIOD: u64 = 60_480;
+/// Maximum number of keys on the admin committee
+const MAX_COMMITTEE_MEMBERS: usize = 16;
+/// Maximum length for a pause or unpause reason
+const MAX_REASON_LEN: usize = 256;
+/// Maximum number of tokens a multi-token voting power strategy can sum
+const MAX_STRATEGY_TOKENS: usize = 8;
+/// Maximum number of proposals in one quadratic voting round
+const MAX_ROUND_PROPOSALS: usize = 50;
+/// Maximum number of candidates in an election (ballots index them with one byte)
+const MAX_CANDIDATES: usize = 32;
+/// Layout version of the contract state, bumped whenever a field is added or changed
+/// 2 added contract voters, 3 voting power strategies, 4 quadratic voting rounds
+/// and 5 council elections
+const STATE_VERSION: u32 = 5;
+/// Denominator for quorum percentages expressed in basis points
+const BASIS_POINTS: u64 = 10_000;
+
+/// Errors returned by fallible contract calls
+///
+/// The discriminants are stable error codes that clients can match on.
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub enum VoteError {
+    ShieldedNotSupported = 1,
+    NotAdmin = 2,
+    CannotProposeSelf = 3,
+    NotPendingAdmin = 4,
+    NoPendingTransfer = 5,
+    MaxProposalsReached = 6,
+    DescriptionTooLong = 7,
+    ProposalNotFound = 8,
+    ContractsCannotVote = 9,
+    NoVotingPower = 10,
+    AlreadyVoted = 11,
+    ProposalCancelled = 12,
+    ProposalAlreadyFinalized = 13,
+    CannotCancelFinalized = 14,
+    VotingNotEnded = 15,
+    InvalidVotingWindow = 16,
+    VotingWindowInPast = 17,
+    VotingNotStarted = 18,
+    VotingEnded = 19,
+    InvalidQuorum = 20,
+    InvalidThreshold = 21,
+    InvalidOptionCount = 22,
+    OptionLabelTooLong = 23,
+    InvalidOption = 24,
+    NotMultiChoice = 25,
+    MultiChoiceProposal = 26,
+    NotVoted = 27,
+    CannotDelegateSelf = 28,
+    DelegationCycle = 29,
+    NotDelegating = 30,
+    CategoryTooLong = 31,
+    EmptyCategory = 32,
+    TooManyActions = 33,
+    ActionTooLarge = 34,
+    NotExecutable = 35,
+    TimelockNotElapsed = 36,
+    AlreadyExecuted = 37,
+    NotQueued = 38,
+    ProposalExpired = 39,
+    TimelockElapsed = 41,
+    ContractsCannotPropose = 42,
+    BelowProposalThreshold = 43,
+    DepositTooLow = 44,
+    DepositNotAccepted = 45,
+    TransferFailed = 46,
+    ProposerOnly = 47,
+    MissingRole = 48,
+    InvalidCommittee = 49,
+    NotCommitteeMember = 50,
+    OperationNotFound = 51,
+    AlreadyApproved = 52,
+    NotSubmitter = 53,
+    ReentrantCall = 54,
+    SelfTargetingAction = 55,
+    Paused = 56,
+    NotPaused = 57,
+    ReasonTooLong = 58,
+    Frozen = 59,
+    UnsettledDeposits = 60,
+    NotSuccessor = 61,
+    StateNotEmpty = 62,
+    UnsupportedStateVersion = 63,
+    MigrationFailed = 64,
+    OutdatedStateVersion = 65,
+    NotContractVoter = 66,
+    InvalidStrategy = 67,
+    QuorumNeedsSupply = 68,
+    RoundNotFound = 69,
+    RoundStarted = 70,
+    RoundProposal = 71,
+    NotRoundProposal = 72,
+    InsufficientCredits = 73,
+    TallyOverflow = 74,
+    RoundFull = 75,
+    ElectionNotFound = 76,
+    InvalidCandidates = 77,
+    InvalidSeats = 78,
+    InvalidRanking = 79,
+    ElectionFinalized = 80,
+}
+
+impl VoteError {
+    /// Stable numeric code of the error
+    pub fn code(&self) -> u16 {
+        *self as u16
+    }
+
+    /// Human-readable description of the error
+    pub fn message(&self) -> &'static str {
+        match self {
+            VoteError::ShieldedNotSupported => "Shielded transactions not supported",
+            VoteError::NotAdmin => "Caller is not admin",
+            VoteError::CannotProposeSelf => "Cannot propose self as new admin",
+            VoteError::NotPendingAdmin => "Caller is not pending admin",
+            VoteError::NoPendingTransfer => "No pending admin transfer",
+            VoteError::MaxProposalsReached => "Maximum proposals reached",
+            VoteError::DescriptionTooLong => "Description too long",
+            VoteError::ProposalNotFound => "Proposal not found",
+            VoteError::ContractsCannotVote => "Only allowlisted contracts can vote",
+            VoteError::NoVotingPower => "No tokens to vote with",
+            VoteError::AlreadyVoted => "Already voted on this proposal",
+            VoteError::ProposalCancelled => "Proposal has been cancelled",
+            VoteError::ProposalAlreadyFinalized => "Proposal outcome already recorded",
+            VoteError::CannotCancelFinalized => "Cannot cancel a finalized proposal",
+            VoteError::VotingNotEnded => "Voting has not ended yet",
+            VoteError::InvalidVotingWindow => "Voting window must end after it starts",
+            VoteError::VotingWindowInPast => "Voting window has already ended",
+            VoteError::VotingNotStarted => "Voting has not started yet",
+            VoteError::VotingEnded => "Voting has ended",
+            VoteError::InvalidQuorum => "Quorum percentage exceeds 100%",
+            VoteError::InvalidThreshold => "Threshold must be a fraction between 0 and 1",
+            VoteError::InvalidOptionCount => {
+                "Multi-choice proposals need between 2 and 10 options"
+            }
+            VoteError::OptionLabelTooLong => "Option label too long",
+            VoteError::InvalidOption => "Option index out of range",
+            VoteError::NotMultiChoice => "Proposal is not multi-choice",
+            VoteError::MultiChoiceProposal => "Multi-choice proposals need an option index",
+            VoteError::NotVoted => "Account has not voted on this proposal",
+            VoteError::CannotDelegateSelf => "Cannot delegate to self",
+            VoteError::DelegationCycle => "Delegation would create a cycle",
+            VoteError::NotDelegating => "Account is not delegating",
+            VoteError::CategoryTooLong => "Category too long",
+            VoteError::EmptyCategory => "Category cannot be empty",
+            VoteError::TooManyActions => "Too many actions",
+            VoteError::ActionTooLarge => "Action function name or argument too long",
+            VoteError::NotExecutable => "Proposal has not succeeded or has no actions",
+            VoteError::TimelockNotElapsed => "Timelock has not elapsed yet",
+            VoteError::AlreadyExecuted => "Proposal already executed",
+            VoteError::NotQueued => "Proposal is not queued",
+            VoteError::ProposalExpired => "Queued proposal has expired",
+            VoteError::TimelockElapsed => "Timelock has already elapsed",
+            VoteError::ContractsCannotPropose => "Contracts cannot create proposals",
+            VoteError::BelowProposalThreshold => "Balance not above the proposal threshold",
+            VoteError::DepositTooLow => "Deposit below the required minimum",
+            VoteError::DepositNotAccepted => {
+                "Deposits need an external creator and a configured treasury"
+            }
+            VoteError::TransferFailed => "DUSK transfer failed",
+            VoteError::ProposerOnly => "Only Proposers can set custom rules, strategies or actions",
+            VoteError::MissingRole => "Account does not hold the required role",
+            VoteError::InvalidCommittee => {
+                "Committee needs 1 to 16 distinct keys and a threshold they can reach"
+            }
+            VoteError::NotCommitteeMember => "Caller is not a committee member",
+            VoteError::OperationNotFound => "Operation not found",
+            VoteError::AlreadyApproved => "Operation already approved by this member",
+            VoteError::NotSubmitter => "Caller did not submit this operation",
+            VoteError::ReentrantCall => "Contracts cannot call in while a committee operation runs",
+            VoteError::SelfTargetingAction => "Actions cannot call the vote contract itself",
+            VoteError::Paused => "Contract is paused",
+            VoteError::NotPaused => "Contract is not paused",
+            VoteError::ReasonTooLong => "Reason too long",
+            VoteError::Frozen => "Contract has been migrated and is read-only",
+            VoteError::UnsettledDeposits => "Live proposals still hold deposits",
+            VoteError::NotSuccessor => "Caller is not the successor contract",
+            VoteError::StateNotEmpty => "Contract already holds state",
+            VoteError::UnsupportedStateVersion => "Snapshot state version is not supported",
+            VoteError::MigrationFailed => "Snapshot could not be read from the previous contract",
+            VoteError::OutdatedStateVersion => "Snapshot state version is too old to import",
+            VoteError::NotContractVoter => "Contract is not on the voter allowlist",
+            VoteError::InvalidStrategy => "Voting power strategy is invalid",
+            VoteError::QuorumNeedsSupply => "Supply-based quorum needs a linear token strategy",
+            VoteError::RoundNotFound => "Round not found",
+            VoteError::RoundStarted => "Round voting has already started",
+            VoteError::RoundProposal => "Round proposals only take quadratic yes/no votes",
+            VoteError::NotRoundProposal => "Proposal is not part of a quadratic voting round",
+            VoteError::InsufficientCredits => "Not enough voice credits left in this round",
+            VoteError::TallyOverflow => "Vote tally would overflow",
+            VoteError::RoundFull => "Round has reached its proposal limit",
+            VoteError::ElectionNotFound => "Election not found",
+            VoteError::InvalidCandidates => "Elections need between 2 and 32 distinct candidates",
+            VoteError::InvalidSeats => "Seats must be at least one and fewer than the candidates",
+            VoteError::InvalidRanking => "Ranking must list distinct candidates of the election",
+            VoteError::ElectionFinalized => "Election has already been finalized",
+        }
+    }
+}
 
-/// Error messages
-mod error {
-    pub const SHIELDED_NOT_SUPPORTED: &str = "Shielded transactions not supported";
-    pub const NOT_ADMIN: &str = "Caller is not admin";
-    pub const CANNOT_PROPOSE_SELF: &str = "Cannot propose self as new admin";
-    pub const NOT_PENDING_ADMIN: &str = "Caller is not pending admin";
-    pub const NO_PENDING_TRANSFER: &str = "No pending admin transfer";
-    pub const MAX_PROPOSALS_REACHED: &str = "Maximum proposals reached";
-    pub const DESCRIPTION_TOO_LONG: &str = "Description too long";
-    pub const PROPOSAL_NOT_FOUND: &str = "Proposal not found";
-    pub const CONTRACTS_CANNOT_VOTE: &str = "Contracts cannot vote";
-    pub const NO_VOTING_POWER: &str = "No tokens to vote with";
-    pub const PROPOSAL_NOT_ACTIVE: &str = "Proposal is not active";
-    pub const ALREADY_VOTED: &str = "Already voted on this proposal";
+impl core::fmt::Display for VoteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
 }
 
 /// Account type - can be either an external account (user) or a contract
@@ -83,637 +324,6049 @@ impl Ord for Account {
 /// - If called directly by a user, returns External with their public key
 /// - If called by another contract, returns Contract with caller ID
 #[cfg(target_family = "wasm")]
-fn sender_account() -> Account {
+fn sender_account() -> Result<Account, VoteError> {
     if abi::callstack().len() == 1 {
-        Account::External(
-            abi::public_sender().expect(error::SHIELDED_NOT_SUPPORTED),
-        )
+        abi::public_sender()
+            .map(Account::External)
+            .ok_or(VoteError::ShieldedNotSupported)
     } else {
-        Account::Contract(abi::caller().expect("ICC expects a caller"))
+        Ok(Account::Contract(abi::caller().expect("ICC expects a caller")))
     }
 }
 
 /// Mock implementation for tests - returns a default account
 /// This allows the contract to compile for unit tests
 #[cfg(not(target_family = "wasm"))]
-fn sender_account() -> Account {
-    Account::External(PublicKey::default())
+fn sender_account() -> Result<Account, VoteError> {
+    Ok(Account::External(PublicKey::default()))
 }
 
-/// Proposal structure
-#[derive(Clone, Default, Archive, Serialize, Deserialize)]
-#[archive_attr(derive(CheckBytes))]
-pub struct Proposal {
-    pub id: u32,
-    pub description: String,
-    pub yes_votes: u64,
-    pub no_votes: u64,
-    pub active: bool,
+/// Get the ID of this contract
+#[cfg(target_family = "wasm")]
+fn self_id() -> ContractId {
+    abi::self_id()
 }
 
-/// The main contract state
-pub struct VoteContract {
-    /// Admin account that can add/close proposals
-    admin: Account,
-    /// Pending admin for two-step transfer (None if no transfer pending)
-    pending_admin: Option<Account>,
-    /// Token contract used for voting weight (e.g., stDUSK)
-    token_contract: ContractId,
-    /// List of proposals
-    proposals: Vec<Proposal>,
-    /// Track who voted on which proposal (proposal_id -> account -> vote_weight)
-    votes: BTreeMap<u32, BTreeMap<Account, u64>>,
-    /// Next proposal ID
-    next_proposal_id: u32,
+/// Mock implementation for tests - returns a fixed contract ID
+#[cfg(not(target_family = "wasm"))]
+fn self_id() -> ContractId {
+    ContractId::from_bytes([0u8; 32])
 }
 
-/// Contract state - persisted automatically by Piecrust
-static mut STATE: VoteContract = VoteContract {
-    admin: Account::Contract(ContractId::from_bytes([0u8; 32])),
-    pending_admin: None,
-    token_contract: ContractId::from_bytes([0u8; 32]),
-    proposals: Vec::new(),
-    votes: BTreeMap::new(),
-    next_proposal_id: 0,
-};
+/// Account the admin committee acts as: this contract itself
+fn committee_account() -> Account {
+    Account::Contract(self_id())
+}
 
-/// Query token balance for an account
+/// Get the current block height
 #[cfg(target_family = "wasm")]
-fn get_token_balance(token_contract: ContractId, public_key: &PublicKey) -> u64 {
-    // Call token contract's balance_of function
-    match abi::call(token_contract, "balance_of", public_key) {
-        Ok(balance) => balance,
-        Err(_) => 0, // Return 0 if call fails
-    }
+fn block_height() -> u64 {
+    abi::block_height()
 }
 
-/// Mock implementation for tests (non-WASM)
+/// Mock implementation for tests - always at genesis
 #[cfg(not(target_family = "wasm"))]
-fn get_token_balance(_token_contract: ContractId, _public_key: &PublicKey) -> u64 {
-    0 // Return 0 in test mode - tests should test contract state directly
+fn block_height() -> u64 {
+    0
 }
 
-impl VoteContract {
-    /// Initialize the contract with specified admin and token contract
-    pub fn init(&mut self, admin: Account, token_contract: ContractId) {
-        self.admin = admin;
-        self.token_contract = token_contract;
-        self.pending_admin = None;
-        self.next_proposal_id = 0;
+/// Lifecycle status of a proposal
+///
+/// Only finalized statuses are stored. Until a proposal is finalized its
+/// status is derived from the voting window: Pending before `start_height`,
+/// Active until `end_height`, then Succeeded or Defeated according to the
+/// tally until `close_proposal` records the outcome.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub enum ProposalStatus {
+    /// Voting has not opened yet (before `start_height`)
+    #[default]
+    Pending,
+    /// Voting is open
+    Active,
+    /// Voting ended and the proposal passed
+    Succeeded,
+    /// Voting ended and the proposal was rejected or missed quorum
+    Defeated,
+    /// Cancelled by the admin before it was finalized
+    Cancelled,
+    /// Succeeded and waiting to be executed
+    Queued,
+    /// Succeeded and executed
+    Executed,
+    /// Queued but not executed in time
+    Expired,
+}
+
+impl ProposalStatus {
+    /// Whether the status is final and no longer derived from the voting window
+    pub fn is_finalized(&self) -> bool {
+        !matches!(self, ProposalStatus::Pending | ProposalStatus::Active)
     }
+}
 
-    // ==================== Admin Transfer Functions (Two-Step Process) ====================
+/// Minimum participation for a proposal result to count
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub enum Quorum {
+    /// Absolute voting weight that must be cast
+    Absolute(u64),
+    /// Share of the token supply at the snapshot height, in basis points
+    SupplyBps(u16),
+}
 
-    /// Propose a new admin (admin only)
-    /// The new admin must call accept_admin() to complete the transfer
-    pub fn propose_admin(&mut self, new_admin: Account) {
-        let caller = sender_account();
-        assert!(caller == self.admin, "{}", error::NOT_ADMIN);
-        assert!(new_admin != self.admin, "{}", error::CANNOT_PROPOSE_SELF);
-        self.pending_admin = Some(new_admin);
-    }
+/// Share of yes votes a proposal needs to pass
+/// A proposal passes when `yes / (yes + no)` is strictly greater than
+/// `numerator / denominator`
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct Threshold {
+    pub numerator: u32,
+    pub denominator: u32,
+}
 
-    /// Accept admin role (pending admin only)
-    /// Completes the two-step admin transfer process
-    pub fn accept_admin(&mut self) {
-        let caller = sender_account();
-        let pending = self.pending_admin.expect(error::NO_PENDING_TRANSFER);
-        assert!(pending == caller, "{}", error::NOT_PENDING_ADMIN);
-        self.admin = caller;
-        self.pending_admin = None;
-    }
+impl Threshold {
+    /// More than half of the votes cast
+    pub const SIMPLE_MAJORITY: Threshold = Threshold { numerator: 1, denominator: 2 };
+    /// More than two thirds of the votes cast
+    pub const SUPERMAJORITY: Threshold = Threshold { numerator: 2, denominator: 3 };
 
-    /// Cancel pending admin transfer (admin only)
-    pub fn cancel_admin_proposal(&mut self) {
-        let caller = sender_account();
-        assert!(caller == self.admin, "{}", error::NOT_ADMIN);
-        assert!(self.pending_admin.is_some(), "{}", error::NO_PENDING_TRANSFER);
-        self.pending_admin = None;
+    /// Check whether the share `part / (part + rest)` exceeds the threshold
+    pub fn is_met(&self, part: u64, rest: u64) -> bool {
+        let total = part as u128 + rest as u128;
+        part as u128 * self.denominator as u128 > total * self.numerator as u128
     }
 
-    /// Get the pending admin (if any)
-    pub fn pending_admin(&self) -> Option<Account> {
-        self.pending_admin
+    /// Check that the threshold is a fraction between 0 and 1
+    fn is_valid(&self) -> bool {
+        self.denominator > 0 && self.numerator <= self.denominator
     }
+}
 
-    // ==================== Proposal Functions (Admin Only) ====================
-
-    /// Add a new proposal (admin only)
-    /// Caller is determined from the call stack
-    pub fn add_proposal(&mut self, description: String) -> u32 {
-        let caller = sender_account();
-        assert!(caller == self.admin, "{}", error::NOT_ADMIN);
-        assert!(self.proposals.len() < MAX_PROPOSALS, "{}", error::MAX_PROPOSALS_REACHED);
-        assert!(description.len() <= MAX_PROPOSAL_DESC_LEN, "{}", error::DESCRIPTION_TOO_LONG);
-
-        let id = self.next_proposal_id;
-        self.next_proposal_id += 1;
+/// Rules used to decide the outcome of a proposal
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct VotingRules {
+    /// Participation (including abstentions) required for the result to count
+    pub quorum: Quorum,
+    /// Share of yes votes among yes, no and veto votes needed to pass
+    pub threshold: Threshold,
+    /// Share of veto votes among all votes that defeats the proposal outright
+    pub veto: Threshold,
+}
 
-        self.proposals.push(Proposal {
-            id,
-            description,
-            yes_votes: 0,
-            no_votes: 0,
-            active: true,
-        });
+impl VotingRules {
+    /// No quorum, simple majority, vetoed by more than a third
+    pub const DEFAULT: VotingRules = VotingRules {
+        quorum: Quorum::Absolute(0),
+        threshold: Threshold::SIMPLE_MAJORITY,
+        veto: Threshold { numerator: 1, denominator: 3 },
+    };
 
-        self.votes.insert(id, BTreeMap::new());
-        id
+    /// Check that the rules can be evaluated
+    fn validate(&self) -> Result<(), VoteError> {
+        if let Quorum::SupplyBps(bps) = self.quorum {
+            if bps as u64 > BASIS_POINTS {
+                return Err(VoteError::InvalidQuorum);
+            }
+        }
+        if !self.threshold.is_valid() || !self.veto.is_valid() {
+            return Err(VoteError::InvalidThreshold);
+        }
+        Ok(())
     }
 
-    /// Close a proposal (admin only)
-    /// Caller is determined from the call stack
-    pub fn close_proposal(&mut self, proposal_id: u32) {
-        let caller = sender_account();
-        assert!(caller == self.admin, "{}", error::NOT_ADMIN);
-        let proposal = self.proposals.iter_mut()
-            .find(|p| p.id == proposal_id)
-            .expect(error::PROPOSAL_NOT_FOUND);
-        proposal.active = false;
+    /// Evaluate the outcome of a tally against these rules
+    /// - quorum_weight: participation required, already resolved from `quorum`
+    pub fn evaluate(
+        &self,
+        yes: u64,
+        no: u64,
+        abstain: u64,
+        no_with_veto: u64,
+        quorum_weight: u64,
+    ) -> ProposalOutcome {
+        let others = yes.saturating_add(no).saturating_add(abstain);
+        if others.saturating_add(no_with_veto) < quorum_weight {
+            ProposalOutcome::QuorumNotMet
+        } else if no_with_veto > 0 && self.veto.is_met(no_with_veto, others) {
+            ProposalOutcome::Vetoed
+        } else if self.threshold.is_met(yes, no.saturating_add(no_with_veto)) {
+            ProposalOutcome::Passed
+        } else {
+            ProposalOutcome::Rejected
+        }
     }
+}
 
-    // ==================== Voting Functions ====================
-
-    /// Vote on a proposal
-    /// Voter is determined from the call stack
-    /// - proposal_id: which proposal to vote on
-    /// - vote_yes: true for yes, false for no
-    pub fn vote(&mut self, proposal_id: u32, vote_yes: bool) {
-        let voter = sender_account();
+impl Default for VotingRules {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
 
-        // Get the public key for token balance lookup
-        let public_key = match voter {
-            Account::External(pk) => pk,
-            Account::Contract(_) => panic!("{}", error::CONTRACTS_CANNOT_VOTE),
-        };
+/// A token counted by a multi-token strategy
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct TokenWeight {
+    /// Token contract exposing `balance_of_account_at` and `total_supply_at`
+    pub token: ContractId,
+    /// Votes per token unit held
+    pub multiplier: u64,
+}
 
-        // Query token balance from the token contract
-        let token_balance = get_token_balance(self.token_contract, &public_key);
-        assert!(token_balance > 0, "{}", error::NO_VOTING_POWER);
+/// Where an account's voting power is read from
+#[derive(Clone, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub enum PowerSource {
+    /// Balance of the voting token when the vote is cast
+    TokenBalance,
+    /// Balance of the voting token at the proposal's snapshot height
+    SnapshotBalance,
+    /// Amount staked with the Dusk stake contract when the vote is cast
+    /// (external accounts only)
+    Staked,
+    /// Sum of snapshot balances over several tokens, each scaled by its multiplier
+    MultiToken(Vec<TokenWeight>),
+    /// One vote for every account registered with a registry contract
+    Registry(ContractId),
+}
 
-        // Check proposal exists and is active
-        let proposal = self.proposals.iter_mut()
-            .find(|p| p.id == proposal_id)
-            .expect(error::PROPOSAL_NOT_FOUND);
-        assert!(proposal.active, "{}", error::PROPOSAL_NOT_ACTIVE);
+/// How the power read from a source is turned into votes
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub enum PowerTransform {
+    /// One vote per unit of power
+    Linear,
+    /// Integer square root of the power, damping large holders (quadratic voting)
+    SquareRoot,
+}
 
-        // Check if already voted
-        let proposal_votes = self.votes.get_mut(&proposal_id)
-            .expect(error::PROPOSAL_NOT_FOUND);
-        assert!(!proposal_votes.contains_key(&voter), "{}", error::ALREADY_VOTED);
-
-        // Record vote with weight = token balance
-        proposal_votes.insert(voter, token_balance);
-        if vote_yes {
-            proposal.yes_votes = proposal.yes_votes.saturating_add(token_balance);
-        } else {
-            proposal.no_votes = proposal.no_votes.saturating_add(token_balance);
+impl PowerTransform {
+    /// Turn power read from a source into votes
+    pub fn apply(&self, power: u64) -> u64 {
+        match self {
+            PowerTransform::Linear => power,
+            PowerTransform::SquareRoot => integer_sqrt(power),
         }
     }
+}
 
-    // ==================== Query Functions ====================
+/// How voting power is determined for a proposal
+#[derive(Clone, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct VotingPowerStrategy {
+    pub source: PowerSource,
+    pub transform: PowerTransform,
+}
 
-    /// Get proposal details
-    pub fn get_proposal(&self, proposal_id: u32) -> Option<Proposal> {
-        self.proposals.iter().find(|p| p.id == proposal_id).cloned()
-    }
+impl VotingPowerStrategy {
+    /// Voting token balance at the snapshot height, one vote per token
+    pub const DEFAULT: VotingPowerStrategy = VotingPowerStrategy {
+        source: PowerSource::SnapshotBalance,
+        transform: PowerTransform::Linear,
+    };
 
-    /// Get all proposals
-    pub fn get_all_proposals(&self) -> Vec<Proposal> {
-        self.proposals.clone()
+    /// Check that the strategy can be evaluated
+    fn validate(&self) -> Result<(), VoteError> {
+        if let PowerSource::MultiToken(tokens) = &self.source {
+            if tokens.is_empty()
+                || tokens.len() > MAX_STRATEGY_TOKENS
+                || tokens.iter().any(|t| t.multiplier == 0)
+            {
+                return Err(VoteError::InvalidStrategy);
+            }
+        }
+        Ok(())
     }
 
-    /// Get proposal count
-    pub fn proposal_count(&self) -> u32 {
-        self.proposals.len() as u32
+    /// Whether the total voting power can be derived from token supplies,
+    /// which a supply-based quorum needs
+    fn has_supply(&self) -> bool {
+        let token_based = matches!(
+            self.source,
+            PowerSource::TokenBalance | PowerSource::SnapshotBalance | PowerSource::MultiToken(_)
+        );
+        token_based && self.transform == PowerTransform::Linear
     }
+}
 
-    /// Check if caller has voted on a proposal
-    pub fn has_voted(&self, proposal_id: u32) -> bool {
-        let voter = sender_account();
-        self.votes
-            .get(&proposal_id)
-            .map(|v| v.contains_key(&voter))
-            .unwrap_or(false)
+impl Default for VotingPowerStrategy {
+    fn default() -> Self {
+        Self::DEFAULT
     }
+}
 
-    /// Check if a specific account has voted on a proposal
-    pub fn has_account_voted(&self, public_key: PublicKey, proposal_id: u32) -> bool {
-        let account = Account::External(public_key);
-        self.votes
-            .get(&proposal_id)
-            .map(|v| v.contains_key(&account))
-            .unwrap_or(false)
+/// Largest integer whose square is at most `n`
+fn integer_sqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
     }
-
-    /// Get vote weight for caller on a proposal (0 if not voted)
-    pub fn get_vote_weight(&self, proposal_id: u32) -> u64 {
-        let voter = sender_account();
-        self.votes
-            .get(&proposal_id)
-            .and_then(|v| v.get(&voter))
-            .copied()
-            .unwrap_or(0)
+    // Newton's method, starting from a power of two above the root
+    let mut x = 1u64 << ((64 - n.leading_zeros()).div_ceil(2));
+    loop {
+        let next = (x + n / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
     }
+}
 
-    /// Get vote weight for a specific account on a proposal (0 if not voted)
-    pub fn get_account_vote_weight(&self, public_key: PublicKey, proposal_id: u32) -> u64 {
-        let account = Account::External(public_key);
-        self.votes
-            .get(&proposal_id)
-            .and_then(|v| v.get(&account))
-            .copied()
-            .unwrap_or(0)
-    }
+/// Final result of a closed proposal
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub enum ProposalOutcome {
+    /// Quorum was met and the approval threshold was exceeded
+    Passed,
+    /// Quorum was met but the approval threshold was not exceeded
+    Rejected,
+    /// Not enough voting weight was cast
+    QuorumNotMet,
+    /// Quorum was met but the veto share was exceeded
+    Vetoed,
+}
 
-    /// Get admin account
-    pub fn admin(&self) -> Account {
-        self.admin
-    }
+/// Ballot option, in the style of Cosmos governance
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub enum VoteOption {
+    /// In favour of the proposal
+    Yes,
+    /// Against the proposal
+    No,
+    /// Counts toward quorum but not toward approval
+    Abstain,
+    /// Against the proposal, and defeats it if the veto share is exceeded
+    NoWithVeto,
+}
 
-    /// Check if caller is admin
-    pub fn is_admin(&self) -> bool {
-        sender_account() == self.admin
-    }
+/// What a voter chose on a proposal
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub enum Choice {
+    /// A ballot option on a yes/no proposal
+    Standard(VoteOption),
+    /// An index into a multi-choice proposal's options
+    MultiChoice(u32),
+}
 
-    /// Get token balance for a public key (queries token contract)
-    pub fn get_balance(&self, public_key: PublicKey) -> u64 {
-        get_token_balance(self.token_contract, &public_key)
-    }
+/// A recorded vote
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct Ballot {
+    pub choice: Choice,
+    pub weight: u64,
+}
 
-    /// Get the token contract ID used for voting weight
-    pub fn token_contract(&self) -> ContractId {
-        self.token_contract
-    }
+/// Which proposals a delegation applies to
+/// The most specific delegation wins: Proposal, then Category, then Global
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub enum DelegationScope {
+    /// Every proposal
+    Global,
+    /// Proposals tagged with this category
+    Category(String),
+    /// A single proposal
+    Proposal(u32),
 }
 
-// ==================== Contract Entry Points ====================
-// These are only compiled for WASM targets
+/// A contract call performed when a proposal is executed
+#[derive(Clone, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct Action {
+    pub target: ContractId,
+    pub function: String,
+    /// rkyv-encoded argument passed to the function
+    pub args: Vec<u8>,
+}
 
-#[cfg(target_family = "wasm")]
-mod entry_points {
-    use super::*;
+/// Result of performing an action
+#[derive(Clone, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub enum ActionResult {
+    /// The call returned these rkyv-encoded bytes
+    Success(Vec<u8>),
+    /// The call failed with this error
+    Failed(String),
+}
 
-/// Initialize contract with specified admin and token contract
-#[no_mangle]
-pub unsafe fn init(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |(admin, token_contract): (Account, ContractId)| {
-        STATE.init(admin, token_contract);
-    })
+/// Permission granted to a set of accounts by the admin
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub enum Role {
+    /// Create proposals without meeting the submission policy
+    Proposer,
+    /// Close proposals once voting has ended
+    Closer,
+    /// Cancel unfinalized proposals, and queued proposals during their timelock
+    Canceller,
+    /// Pause and unpause the contract
+    Pauser,
+    /// Change voting rules, archiving, timelock and submission settings
+    ConfigManager,
+    /// Elected council seat, filled by elections that install their winners
+    Council,
 }
 
-/// Add proposal (admin only) - caller determined from call stack
-#[no_mangle]
-pub unsafe fn add_proposal(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |description: String| {
-        STATE.add_proposal(description)
-    })
+impl Role {
+    /// Every permission role, granted to the admin on initialization (the Council is elected)
+    pub const ALL: [Role; 5] =
+        [Role::Proposer, Role::Closer, Role::Canceller, Role::Pauser, Role::ConfigManager];
 }
 
-/// Close proposal (admin only) - caller determined from call stack
-#[no_mangle]
-pub unsafe fn close_proposal(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |proposal_id: u32| {
-        STATE.close_proposal(proposal_id)
-    })
+/// Who may create proposals and what they must put up to do so
+/// Proposers can always create proposals without meeting the threshold
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct SubmissionPolicy {
+    /// Whether external accounts without the Proposer role can create proposals
+    pub permissionless: bool,
+    /// stDUSK balance a creator without the Proposer role must exceed
+    pub threshold: u64,
+    /// DUSK deposit a creator without the Proposer role must lock
+    pub min_deposit: u64,
+    /// Account receiving slashed deposits (None disables deposits)
+    pub treasury: Option<PublicKey>,
 }
 
-/// Vote on proposal - voter determined from call stack
-#[no_mangle]
-pub unsafe fn vote(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |(proposal_id, vote_yes): (u32, bool)| {
-        STATE.vote(proposal_id, vote_yes)
-    })
+impl SubmissionPolicy {
+    /// Only Proposers create proposals, without deposits
+    pub const ADMIN_ONLY: SubmissionPolicy = SubmissionPolicy {
+        permissionless: false,
+        threshold: 0,
+        min_deposit: 0,
+        treasury: None,
+    };
 }
 
-/// Get proposal by ID
-#[no_mangle]
-pub unsafe fn get_proposal(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |proposal_id: u32| STATE.get_proposal(proposal_id))
+impl Default for SubmissionPolicy {
+    fn default() -> Self {
+        Self::ADMIN_ONLY
+    }
 }
 
-/// Get all proposals
-#[no_mangle]
-pub unsafe fn get_all_proposals(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |_: ()| STATE.get_all_proposals())
+/// Keys that jointly act as the admin
+/// An operation is carried out once `threshold` members have approved it
+#[derive(Clone, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct Committee {
+    pub members: Vec<PublicKey>,
+    pub threshold: u32,
 }
 
-/// Get proposal count
-#[no_mangle]
-pub unsafe fn proposal_count(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |_: ()| STATE.proposal_count())
+impl Committee {
+    /// Check that the members are distinct and can reach the threshold
+    fn validate(&self) -> Result<(), VoteError> {
+        let count = self.members.len();
+        let distinct =
+            self.members.iter().enumerate().all(|(i, pk)| !self.members[..i].contains(pk));
+        if count == 0 || count > MAX_COMMITTEE_MEMBERS || !distinct {
+            return Err(VoteError::InvalidCommittee);
+        }
+        if self.threshold == 0 || self.threshold as usize > count {
+            return Err(VoteError::InvalidCommittee);
+        }
+        Ok(())
+    }
 }
 
-/// Check if caller has voted on proposal
-#[no_mangle]
-pub unsafe fn has_voted(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |proposal_id: u32| {
-        STATE.has_voted(proposal_id)
-    })
+/// A call the committee makes as the admin
+#[derive(Clone, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub enum AdminOperation {
+    AddProposal(NewProposal),
+    CloseProposal(u32),
+    CancelProposal(u32),
+    CancelQueued(u32),
+    ProposeAdmin(Account),
+    CancelAdminProposal,
+    GrantRole(Role, Account),
+    RevokeRole(Role, Account),
+    SetVotingRules(VotingRules),
+    SetVotingPowerStrategy(VotingPowerStrategy),
+    CreateRound(NewRound),
+    CreateElection(NewElection),
+    FinalizeElection(u32),
+    SetArchiveAge(u64),
+    SetTimelock(u64, u64),
+    SetSubmissionPolicy(SubmissionPolicy),
+    AllowContractVoter(ContractId),
+    RemoveContractVoter(ContractId),
+    SetCommittee(Committee),
+    Pause(String),
+    Unpause(String),
+    Freeze(ContractId),
+    MigrateFrom(ContractId),
 }
 
-/// Check if specific account has voted on proposal
-#[no_mangle]
-pub unsafe fn has_account_voted(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |(public_key, proposal_id): (PublicKey, u32)| {
-        STATE.has_account_voted(public_key, proposal_id)
-    })
+/// An operation waiting for committee approvals
+#[derive(Clone, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct PendingOperation {
+    pub id: u32,
+    pub operation: AdminOperation,
+    /// Member that submitted the operation (and approved it by doing so)
+    pub submitter: PublicKey,
+    /// Members that approved the operation, in approval order
+    pub approvals: Vec<PublicKey>,
 }
 
-/// Get vote weight for caller on proposal
-#[no_mangle]
-pub unsafe fn get_vote_weight(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |proposal_id: u32| {
-        STATE.get_vote_weight(proposal_id)
-    })
+/// Per-proposal map flattened for a state snapshot (proposal_id, [(key, value)])
+pub type ProposalEntries<K, V> = Vec<(u32, Vec<(K, V)>)>;
+
+/// Everything a successor deployment imports from a frozen contract
+/// Maps are flattened to lists; pending committee operations are not carried over,
+/// since they were approved against this contract and their layout changes with it
+#[derive(Clone, Archive, Serialize, Deserialize)]
+#[archive_attr(derive(CheckBytes))]
+pub struct StateSnapshot {
+    /// Layout version of the exporting contract
+    pub version: u32,
+    /// Contract that exported the state
+    pub source: ContractId,
+    /// Contract the state was exported to
+    pub successor: ContractId,
+    pub admin: Account,
+    pub token_contract: ContractId,
+    pub voting_rules: VotingRules,
+    pub voting_power_strategy: VotingPowerStrategy,
+    pub proposals: Vec<Proposal>,
+    /// Ballots per proposal (voter -> ballot)
+    pub votes: ProposalEntries<Account, Ballot>,
+    /// Delegations ((delegator, scope), delegate)
+    pub delegates: Vec<((Account, DelegationScope), Account)>,
+    /// Delegated weight per proposal (delegator -> (voter, weight))
+    pub delegated_votes: ProposalEntries<Account, (Account, u64)>,
+    pub next_proposal_id: u32,
+    pub archived: Vec<ProposalRecord>,
+    pub archive_age: u64,
+    pub execution_delay: u64,
+    pub grace_period: u64,
+    pub roles: Vec<(Role, Vec<Account>)>,
+    pub submission_policy: SubmissionPolicy,
+    pub contract_voters: Vec<ContractId>,
+    pub committee: Option<Committee>,
+    pub paused: bool,
+    pub rounds: Vec<Round>,
+    pub next_round_id: u32,
+    /// Voice credits spent per (round_id, voter)
+    pub credits_spent: Vec<((u32, Account), u64)>,
+    pub elections: Vec<Election>,
+    pub next_election_id: u32,
+    /// Ranked ballots per election (voter -> ballot)
+    pub election_ballots: ProposalEntries<Account, RankedBallot>,
+}
+
+/// Snapshot exported at state version 4, before council elections
+#[derive(Clone, Archive, Serialize, Deserialize)]
+#[archive_attr(derive(CheckBytes))]
+pub struct StateSnapshotV4 {
+    pub version: u32,
+    pub source: ContractId,
+    pub successor: ContractId,
+    pub admin: Account,
+    pub token_contract: ContractId,
+    pub voting_rules: VotingRules,
+    pub voting_power_strategy: VotingPowerStrategy,
+    pub proposals: Vec<Proposal>,
+    pub votes: ProposalEntries<Account, Ballot>,
+    pub delegates: Vec<((Account, DelegationScope), Account)>,
+    pub delegated_votes: ProposalEntries<Account, (Account, u64)>,
+    pub next_proposal_id: u32,
+    pub archived: Vec<ProposalRecord>,
+    pub archive_age: u64,
+    pub execution_delay: u64,
+    pub grace_period: u64,
+    pub roles: Vec<(Role, Vec<Account>)>,
+    pub submission_policy: SubmissionPolicy,
+    pub contract_voters: Vec<ContractId>,
+    pub committee: Option<Committee>,
+    pub paused: bool,
+    pub rounds: Vec<Round>,
+    pub next_round_id: u32,
+    pub credits_spent: Vec<((u32, Account), u64)>,
+}
+
+impl From<StateSnapshotV4> for StateSnapshot {
+    /// There were no council elections before version 5
+    fn from(old: StateSnapshotV4) -> Self {
+        StateSnapshot {
+            version: old.version,
+            source: old.source,
+            successor: old.successor,
+            admin: old.admin,
+            token_contract: old.token_contract,
+            voting_rules: old.voting_rules,
+            voting_power_strategy: old.voting_power_strategy,
+            proposals: old.proposals,
+            votes: old.votes,
+            delegates: old.delegates,
+            delegated_votes: old.delegated_votes,
+            next_proposal_id: old.next_proposal_id,
+            archived: old.archived,
+            archive_age: old.archive_age,
+            execution_delay: old.execution_delay,
+            grace_period: old.grace_period,
+            roles: old.roles,
+            submission_policy: old.submission_policy,
+            contract_voters: old.contract_voters,
+            committee: old.committee,
+            paused: old.paused,
+            rounds: old.rounds,
+            next_round_id: old.next_round_id,
+            credits_spent: old.credits_spent,
+            elections: Vec::new(),
+            next_election_id: 0,
+            election_ballots: Vec::new(),
+        }
+    }
+}
+
+/// Parameters for creating a proposal
+#[derive(Clone, Default, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct NewProposal {
+    pub description: String,
+    /// First block height at which votes are accepted
+    pub start_height: u64,
+    /// Block height at which voting closes (exclusive)
+    pub end_height: u64,
+    /// Quorum and threshold for this proposal (None for the global rules)
+    pub rules: Option<VotingRules>,
+    /// How voting power is determined (None for the global strategy)
+    /// Round proposals always use the default strategy, which funds the credits
+    pub strategy: Option<VotingPowerStrategy>,
+    /// Option labels for a multi-choice proposal (empty for yes/no)
+    pub options: Vec<String>,
+    /// Category tag used for scoped delegation (empty for uncategorised)
+    pub category: String,
+    /// Contract calls performed if the proposal succeeds
+    pub actions: Vec<Action>,
+    /// DUSK locked by the creator, which must be attached to the call
+    pub deposit: u64,
+    /// Quadratic voting round the proposal joins (None for a regular proposal)
+    pub round: Option<u32>,
+}
+
+/// Proposal structure
+#[derive(Clone, Default, Archive, Serialize, Deserialize)]
+#[archive_attr(derive(CheckBytes))]
+pub struct Proposal {
+    pub id: u32,
+    pub description: String,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    /// First block height at which votes are accepted
+    pub start_height: u64,
+    /// Block height at which voting closes (exclusive)
+    pub end_height: u64,
+    /// Block height at which voting power is read from the token contract
+    pub snapshot_height: u64,
+    /// Quorum and threshold this proposal is evaluated against
+    pub rules: VotingRules,
+    /// Result recorded when the proposal is closed
+    pub outcome: Option<ProposalOutcome>,
+    /// Lifecycle status (derived at the queried block height until finalized)
+    pub status: ProposalStatus,
+    /// Weight of abstaining votes
+    pub abstain_votes: u64,
+    /// Weight of no-with-veto votes (not included in `no_votes`)
+    pub no_with_veto_votes: u64,
+    /// Option labels of a multi-choice proposal (empty for yes/no)
+    pub options: Vec<String>,
+    /// Weighted tally per option, in the same order as `options`
+    pub option_votes: Vec<u64>,
+    /// Option with the most votes, recorded when a multi-choice proposal is closed
+    pub winning_option: Option<u32>,
+    /// Category tag used for scoped delegation (empty for uncategorised)
+    pub category: String,
+    /// Account that created the proposal
+    pub creator: Account,
+    /// Contract calls performed when the proposal is executed
+    pub actions: Vec<Action>,
+    /// Block height from which a queued proposal can be executed
+    pub execute_after: u64,
+    /// Result of each action performed, in order; execution stops at the first failure
+    pub action_results: Vec<ActionResult>,
+    /// Block height at which a queued proposal that was not executed expires
+    pub expires_at: u64,
+    /// DUSK locked by the creator, refunded or slashed when the proposal is finalized
+    pub deposit: u64,
+    /// How voting power is determined for this proposal
+    pub strategy: VotingPowerStrategy,
+    /// Quadratic voting round the proposal belongs to (None for a regular proposal)
+    pub round: Option<u32>,
+}
+
+/// Parameters for creating a quadratic voting round
+#[derive(Clone, Default, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct NewRound {
+    pub description: String,
+    /// First block height at which votes are accepted
+    pub start_height: u64,
+    /// Block height at which voting closes (exclusive)
+    pub end_height: u64,
+}
+
+/// A group of yes/no proposals sharing one voice-credit budget per voter
+/// Each voter's credits are their stDUSK balance at the round's snapshot height,
+/// and casting n votes on a proposal costs n² credits
+#[derive(Clone, Default, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct Round {
+    pub id: u32,
+    pub description: String,
+    /// First block height at which votes are accepted, shared by every proposal
+    pub start_height: u64,
+    /// Block height at which voting closes (exclusive), shared by every proposal
+    pub end_height: u64,
+    /// Block height at which voice credits are read from the token contract
+    pub snapshot_height: u64,
+    /// Proposals in the round, in the order they joined
+    pub proposal_ids: Vec<u32>,
+}
+
+/// Voice credits needed to cast `votes` quadratic votes
+fn quadratic_cost(votes: u64) -> Result<u64, VoteError> {
+    votes.checked_mul(votes).ok_or(VoteError::TallyOverflow)
+}
+
+/// Quadratic votes that `credits` voice credits buy (the integer square root)
+fn quadratic_votes(credits: u64) -> u64 {
+    if credits < 2 {
+        return credits;
+    }
+    // Newton's iteration decreases from above until it settles on the floor of the root
+    let mut root = credits / 2 + 1;
+    let mut next = (root + credits / root) / 2;
+    while next < root {
+        root = next;
+        next = (root + credits / root) / 2;
+    }
+    root
+}
+
+/// Parameters for creating a council election
+#[derive(Clone, Default, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct NewElection {
+    pub description: String,
+    /// First block height at which ballots are accepted
+    pub start_height: u64,
+    /// Block height at which voting closes (exclusive)
+    pub end_height: u64,
+    /// Accounts standing for election, in the order ballots index them
+    pub candidates: Vec<Account>,
+    /// Number of seats to fill (one seat is counted by instant-runoff)
+    pub seats: u32,
+    /// Whether the winners replace the holders of the Council role
+    pub install_council: bool,
+}
+
+/// An election of one or more candidates by ranked ballots
+#[derive(Clone, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct Election {
+    pub id: u32,
+    pub description: String,
+    /// Accounts standing for election, in the order ballots index them
+    pub candidates: Vec<Account>,
+    /// Number of seats to fill
+    pub seats: u32,
+    /// First block height at which ballots are accepted
+    pub start_height: u64,
+    /// Block height at which voting closes (exclusive)
+    pub end_height: u64,
+    /// Block height at which voting power is read
+    pub snapshot_height: u64,
+    /// How voting power is determined (the global strategy when the election was created)
+    pub strategy: VotingPowerStrategy,
+    /// Whether the winners replace the holders of the Council role
+    pub install_council: bool,
+    /// Sum of the weights of every ballot cast
+    pub total_weight: u64,
+    /// Whether the ballots have been counted
+    pub finalized: bool,
+    /// Elected candidates in the order they were elected, recorded when finalized
+    pub winners: Vec<Account>,
+}
+
+/// A ranked ballot, stored as one byte per ranked candidate
+#[derive(Clone, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct RankedBallot {
+    /// Indices into the election's candidates, most preferred first
+    pub ranking: Vec<u8>,
+    pub weight: u64,
+}
+
+/// Check that no item appears twice
+fn all_distinct<T: PartialEq>(items: &[T]) -> bool {
+    items.iter().enumerate().all(|(i, item)| !items[..i].contains(item))
+}
+
+/// Count ranked ballots by weighted single transferable vote
+/// Returns the indices of the elected candidates in the order they were elected
+///
+/// A candidate reaching the Droop quota is elected, and every ballot counting for them
+/// moves on to its next continuing choice at a value reduced to share out the surplus
+/// (Gregory method, rounded down). Otherwise the lowest candidate is eliminated, until
+/// the continuing candidates just fill the remaining seats. With one seat the quota is a
+/// majority, so this is instant-runoff. Ties elect the earlier candidate and eliminate
+/// the later one. The ballot weights must sum to a `u64`, which `vote_ranked` enforces.
+fn count_stv(candidate_count: usize, ballots: &[RankedBallot], seats: usize) -> Vec<u8> {
+    let total: u64 = ballots.iter().map(|ballot| ballot.weight).sum();
+    let quota = total / (seats as u64 + 1) + 1;
+    let mut values: Vec<u64> = ballots.iter().map(|ballot| ballot.weight).collect();
+    let mut continuing = alloc::vec![true; candidate_count];
+    let mut elected = Vec::new();
+
+    while elected.len() < seats {
+        let top = |ranking: &[u8]| ranking.iter().copied().find(|c| continuing[*c as usize]);
+        let mut tallies = alloc::vec![0u64; candidate_count];
+        for (ballot, value) in ballots.iter().zip(&values) {
+            if let Some(candidate) = top(&ballot.ranking) {
+                tallies[candidate as usize] += value;
+            }
+        }
+
+        // Highest tally first, earlier candidates first on a tie
+        let mut standing: Vec<u8> = (0..candidate_count as u8)
+            .filter(|c| continuing[*c as usize])
+            .collect();
+        standing.sort_by_key(|c| (core::cmp::Reverse(tallies[*c as usize]), *c));
+        if standing.len() <= seats - elected.len() {
+            elected.extend(standing);
+            break;
+        }
+
+        let leader = standing[0];
+        let tally = tallies[leader as usize];
+        if tally >= quota {
+            let surplus = tally - quota;
+            for (ballot, value) in ballots.iter().zip(values.iter_mut()) {
+                if top(&ballot.ranking) == Some(leader) {
+                    *value = (u128::from(*value) * u128::from(surplus) / u128::from(tally)) as u64;
+                }
+            }
+            continuing[leader as usize] = false;
+            elected.push(leader);
+        } else if let Some(last) = standing.last() {
+            continuing[*last as usize] = false;
+        }
+    }
+    elected
+}
+
+/// Criteria for `get_proposals` (unset fields match every proposal)
+#[derive(Clone, Default, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct ProposalFilter {
+    /// Status derived at the current block height
+    pub status: Option<ProposalStatus>,
+    pub creator: Option<Account>,
+    pub category: Option<String>,
+}
+
+/// A finalized proposal moved out of live storage
+/// Its ballots are freed, leaving a commitment to the final vote set
+#[derive(Clone, Archive, Serialize, Deserialize)]
+#[archive_attr(derive(CheckBytes))]
+pub struct ProposalRecord {
+    /// The proposal with its final status and tallies
+    pub proposal: Proposal,
+    /// Number of ballots cast
+    pub voter_count: u32,
+    /// Hash of every (voter, choice, weight) ballot in account order
+    pub votes_commitment: [u8; 32],
+}
+
+impl Proposal {
+    /// Check whether votes are accepted at the given block height
+    /// Returns the error explaining why voting is not possible
+    fn check_voting_open(&self, height: u64) -> Result<(), VoteError> {
+        match self.status {
+            ProposalStatus::Pending | ProposalStatus::Active => (),
+            ProposalStatus::Cancelled => return Err(VoteError::ProposalCancelled),
+            _ => return Err(VoteError::VotingEnded),
+        }
+        if height < self.start_height {
+            return Err(VoteError::VotingNotStarted);
+        }
+        if height >= self.end_height {
+            return Err(VoteError::VotingEnded);
+        }
+        Ok(())
+    }
+
+    /// Whether voters pick from a list of options instead of yes/no
+    pub fn is_multi_choice(&self) -> bool {
+        !self.options.is_empty()
+    }
+
+    /// Check that a choice fits this proposal
+    /// Round proposals are only voted on through `vote_quadratic`
+    fn check_choice(&self, choice: Choice) -> Result<(), VoteError> {
+        if self.round.is_some() {
+            return Err(VoteError::RoundProposal);
+        }
+        match choice {
+            Choice::Standard(_) if self.is_multi_choice() => Err(VoteError::MultiChoiceProposal),
+            Choice::Standard(_) => Ok(()),
+            Choice::MultiChoice(_) if !self.is_multi_choice() => Err(VoteError::NotMultiChoice),
+            Choice::MultiChoice(index) if index as usize >= self.options.len() => {
+                Err(VoteError::InvalidOption)
+            }
+            Choice::MultiChoice(_) => Ok(()),
+        }
+    }
+
+    /// Get the weight counted for a choice
+    fn tally(&self, choice: Choice) -> u64 {
+        match choice {
+            Choice::Standard(VoteOption::Yes) => self.yes_votes,
+            Choice::Standard(VoteOption::No) => self.no_votes,
+            Choice::Standard(VoteOption::Abstain) => self.abstain_votes,
+            Choice::Standard(VoteOption::NoWithVeto) => self.no_with_veto_votes,
+            Choice::MultiChoice(index) => self.option_votes[index as usize],
+        }
+    }
+
+    /// Get the tally a choice is counted in
+    /// The choice must have passed `check_choice`
+    fn tally_mut(&mut self, choice: Choice) -> &mut u64 {
+        match choice {
+            Choice::Standard(VoteOption::Yes) => &mut self.yes_votes,
+            Choice::Standard(VoteOption::No) => &mut self.no_votes,
+            Choice::Standard(VoteOption::Abstain) => &mut self.abstain_votes,
+            Choice::Standard(VoteOption::NoWithVeto) => &mut self.no_with_veto_votes,
+            Choice::MultiChoice(index) => &mut self.option_votes[index as usize],
+        }
+    }
+}
+
+/// Get the option with strictly the most votes (None on a tie or no votes)
+fn plurality_winner(option_votes: &[u64]) -> Option<u32> {
+    let max = option_votes.iter().copied().max().filter(|max| *max > 0)?;
+    let mut leaders = option_votes.iter().enumerate().filter(|(_, v)| **v == max);
+    let (index, _) = leaders.next()?;
+    match leaders.next() {
+        Some(_) => None,
+        None => Some(index as u32),
+    }
+}
+
+/// Proposal layout returned by `get_proposal` before ballot options and lifecycles,
+/// kept for clients that still decode it
+#[derive(Clone, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct ProposalV1 {
+    pub id: u32,
+    pub description: String,
+    pub yes_votes: u64,
+    /// Weight of no votes, including no-with-veto votes
+    pub no_votes: u64,
+    /// Whether votes can still be cast or are yet to open
+    pub active: bool,
+}
+
+impl From<&Proposal> for ProposalV1 {
+    fn from(proposal: &Proposal) -> Self {
+        ProposalV1 {
+            id: proposal.id,
+            description: proposal.description.clone(),
+            yes_votes: proposal.yes_votes,
+            no_votes: proposal.no_votes.saturating_add(proposal.no_with_veto_votes),
+            active: matches!(proposal.status, ProposalStatus::Pending | ProposalStatus::Active),
+        }
+    }
+}
+
+// ==================== Events ====================
+
+/// Emitted when a proposal is created
+#[derive(Clone, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct ProposalCreated {
+    pub proposal_id: u32,
+    pub creator: Account,
+    pub start_height: u64,
+    pub end_height: u64,
+    pub snapshot_height: u64,
+    /// Number of options (0 for a yes/no proposal)
+    pub option_count: u32,
+    pub category: String,
+}
+
+impl ProposalCreated {
+    pub const TOPIC: &'static str = "proposal_created";
+}
+
+/// Emitted when a proposal's outcome is recorded
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct ProposalClosed {
+    pub proposal_id: u32,
+    pub outcome: ProposalOutcome,
+    pub winning_option: Option<u32>,
+}
+
+impl ProposalClosed {
+    pub const TOPIC: &'static str = "proposal_closed";
+}
+
+/// Emitted when a proposal is cancelled
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct ProposalCancelled {
+    pub proposal_id: u32,
+}
+
+impl ProposalCancelled {
+    pub const TOPIC: &'static str = "proposal_cancelled";
+}
+
+/// Emitted when a quadratic voting round is created
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct RoundCreated {
+    pub round_id: u32,
+    pub start_height: u64,
+    pub end_height: u64,
+    pub snapshot_height: u64,
+}
+
+impl RoundCreated {
+    pub const TOPIC: &'static str = "round_created";
+}
+
+/// Emitted when a council election is created
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct ElectionCreated {
+    pub election_id: u32,
+    pub seats: u32,
+    pub candidate_count: u32,
+    pub start_height: u64,
+    pub end_height: u64,
+    pub snapshot_height: u64,
+}
+
+impl ElectionCreated {
+    pub const TOPIC: &'static str = "election_created";
+}
+
+/// Emitted when a ranked ballot is cast (the ranking is read with `get_ranked_ballot`)
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct RankedBallotCast {
+    pub election_id: u32,
+    pub voter: Account,
+    pub weight: u64,
+}
+
+impl RankedBallotCast {
+    pub const TOPIC: &'static str = "ranked_ballot_cast";
+}
+
+/// Emitted when an election's ballots are counted
+#[derive(Clone, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct ElectionFinalized {
+    pub election_id: u32,
+    pub winners: Vec<Account>,
+}
+
+impl ElectionFinalized {
+    pub const TOPIC: &'static str = "election_finalized";
+}
+
+/// Emitted when a ballot is cast, changed or retracted
+/// `weight` includes delegated weight; a retraction has no choice
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct VoteCast {
+    pub proposal_id: u32,
+    pub voter: Account,
+    pub choice: Option<Choice>,
+    pub weight: u64,
+}
+
+impl VoteCast {
+    pub const TOPIC: &'static str = "vote_cast";
+    pub const CHANGED_TOPIC: &'static str = "vote_changed";
+    pub const RETRACTED_TOPIC: &'static str = "vote_retracted";
+}
+
+/// Emitted when a delegation is set or removed (`delegate` is None on removal)
+#[derive(Clone, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct DelegationChanged {
+    pub delegator: Account,
+    pub scope: DelegationScope,
+    pub delegate: Option<Account>,
+}
+
+impl DelegationChanged {
+    pub const TOPIC: &'static str = "delegation_changed";
+}
+
+/// Emitted when the default voting rules are replaced
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct VotingRulesUpdated {
+    pub rules: VotingRules,
+}
+
+impl VotingRulesUpdated {
+    pub const TOPIC: &'static str = "voting_rules_updated";
+}
+
+/// Emitted when a proposal's actions are performed
+#[derive(Clone, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct ProposalExecuted {
+    pub proposal_id: u32,
+    pub results: Vec<ActionResult>,
+}
+
+impl ProposalExecuted {
+    pub const TOPIC: &'static str = "proposal_executed";
+}
+
+/// Emitted when a succeeded proposal enters the timelock queue
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct ProposalQueued {
+    pub proposal_id: u32,
+    pub execute_after: u64,
+    pub expires_at: u64,
+}
+
+impl ProposalQueued {
+    pub const TOPIC: &'static str = "proposal_queued";
+}
+
+/// Emitted when the timelock delay or grace period is changed
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct TimelockUpdated {
+    pub execution_delay: u64,
+    pub grace_period: u64,
+}
+
+impl TimelockUpdated {
+    pub const TOPIC: &'static str = "timelock_updated";
+}
+
+/// Emitted when the default voting power strategy is replaced
+#[derive(Clone, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct VotingPowerStrategyUpdated {
+    pub strategy: VotingPowerStrategy,
+}
+
+impl VotingPowerStrategyUpdated {
+    pub const TOPIC: &'static str = "voting_power_strategy_updated";
+}
+
+/// Emitted when who may create proposals is changed
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct SubmissionPolicyUpdated {
+    pub policy: SubmissionPolicy,
+}
+
+impl SubmissionPolicyUpdated {
+    pub const TOPIC: &'static str = "submission_policy_updated";
+}
+
+/// Emitted when a proposal deposit is returned to its creator or sent to the treasury
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct DepositSettled {
+    pub proposal_id: u32,
+    pub recipient: PublicKey,
+    pub amount: u64,
+}
+
+impl DepositSettled {
+    pub const REFUNDED_TOPIC: &'static str = "deposit_refunded";
+    pub const SLASHED_TOPIC: &'static str = "deposit_slashed";
+}
+
+/// Emitted when a finalized proposal is archived
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct ProposalArchived {
+    pub proposal_id: u32,
+    pub voter_count: u32,
+    pub votes_commitment: [u8; 32],
+}
+
+impl ProposalArchived {
+    pub const TOPIC: &'static str = "proposal_archived";
+}
+
+/// Emitted when the archive age is changed
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct ArchiveAgeUpdated {
+    pub archive_age: u64,
+}
+
+impl ArchiveAgeUpdated {
+    pub const TOPIC: &'static str = "archive_age_updated";
+}
+
+/// Emitted when a contract is added to or removed from the voter allowlist
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct ContractVoterChanged {
+    pub contract: ContractId,
+}
+
+impl ContractVoterChanged {
+    pub const ALLOWED_TOPIC: &'static str = "contract_voter_allowed";
+    pub const REMOVED_TOPIC: &'static str = "contract_voter_removed";
+}
+
+/// Emitted when a role is granted to or revoked from an account
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct RoleChanged {
+    pub role: Role,
+    pub account: Account,
+}
+
+impl RoleChanged {
+    pub const GRANTED_TOPIC: &'static str = "role_granted";
+    pub const REVOKED_TOPIC: &'static str = "role_revoked";
+}
+
+/// Emitted when a committee member submits or approves an operation
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct OperationApproved {
+    pub operation_id: u32,
+    pub member: PublicKey,
+    /// Approvals so far, including this one
+    pub approvals: u32,
+}
+
+impl OperationApproved {
+    pub const TOPIC: &'static str = "operation_approved";
+    pub const SUBMITTED_TOPIC: &'static str = "operation_submitted";
+}
+
+/// Emitted when a pending operation is carried out or withdrawn
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct OperationClosed {
+    pub operation_id: u32,
+}
+
+impl OperationClosed {
+    pub const EXECUTED_TOPIC: &'static str = "operation_executed";
+    pub const CANCELLED_TOPIC: &'static str = "operation_cancelled";
+}
+
+/// Emitted when the admin committee is set or replaced
+#[derive(Clone, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct CommitteeUpdated {
+    pub committee: Committee,
+}
+
+impl CommitteeUpdated {
+    pub const TOPIC: &'static str = "committee_updated";
 }
 
-/// Get vote weight for specific account on proposal
-#[no_mangle]
-pub unsafe fn get_account_vote_weight(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |(public_key, proposal_id): (PublicKey, u32)| {
-        STATE.get_account_vote_weight(public_key, proposal_id)
-    })
-}
+/// Emitted when the contract is paused or unpaused
+#[derive(Clone, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct PauseChanged {
+    pub account: Account,
+    pub reason: String,
+}
+
+impl PauseChanged {
+    pub const PAUSED_TOPIC: &'static str = "paused";
+    pub const UNPAUSED_TOPIC: &'static str = "unpaused";
+}
+
+/// Emitted when the contract is frozen for migration to a successor
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct ContractFrozen {
+    pub successor: ContractId,
+}
+
+impl ContractFrozen {
+    pub const TOPIC: &'static str = "contract_frozen";
+}
+
+/// Emitted when state is imported from a frozen contract
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct StateMigrated {
+    pub source: ContractId,
+    pub version: u32,
+    pub proposal_count: u32,
+    pub archived_count: u32,
+}
+
+impl StateMigrated {
+    pub const TOPIC: &'static str = "state_migrated";
+}
+
+/// Emitted when the admin proposes a successor
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct AdminProposed {
+    pub admin: Account,
+    pub pending_admin: Account,
+}
+
+impl AdminProposed {
+    pub const TOPIC: &'static str = "admin_proposed";
+}
+
+/// Emitted when the pending admin accepts the role
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct AdminAccepted {
+    pub previous_admin: Account,
+    pub admin: Account,
+}
+
+impl AdminAccepted {
+    pub const TOPIC: &'static str = "admin_accepted";
+}
+
+/// Emitted when a pending admin transfer is cancelled
+#[derive(Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize, Debug)]
+#[archive_attr(derive(CheckBytes))]
+pub struct AdminTransferCancelled {
+    pub admin: Account,
+    pub pending_admin: Account,
+}
+
+impl AdminTransferCancelled {
+    pub const TOPIC: &'static str = "admin_transfer_cancelled";
+}
+
+/// Emit an event under a topic
+#[cfg(target_family = "wasm")]
+fn emit<E>(topic: &'static str, event: E)
+where
+    E: for<'a> Serialize<abi::StandardBufSerializer<'a>>,
+{
+    abi::emit(topic, event);
+}
+
+/// Mock implementation for tests - events are dropped
+#[cfg(not(target_family = "wasm"))]
+fn emit<E>(_topic: &'static str, _event: E) {}
+
+/// The main contract state
+pub struct VoteContract {
+    /// Layout version of this state (see `STATE_VERSION`)
+    state_version: u32,
+    /// Admin account that grants and revokes roles
+    admin: Account,
+    /// Pending admin for two-step transfer (None if no transfer pending)
+    pending_admin: Option<Account>,
+    /// Token contract used for voting weight (e.g., stDUSK)
+    token_contract: ContractId,
+    /// Rules applied to proposals created without their own
+    voting_rules: VotingRules,
+    /// Voting power strategy applied to proposals created without their own
+    voting_power_strategy: VotingPowerStrategy,
+    /// Live (not archived) proposals by ID
+    proposals: BTreeMap<u32, Proposal>,
+    /// Track who voted on which proposal (proposal_id -> account -> ballot)
+    votes: BTreeMap<u32, BTreeMap<Account, Ballot>>,
+    /// Who each account delegates its voting power to ((delegator, scope) -> delegate)
+    delegates: BTreeMap<(Account, DelegationScope), Account>,
+    /// Reverse index of `delegates` ((delegate, scope) -> direct delegators)
+    delegators: BTreeMap<(Account, DelegationScope), BTreeSet<Account>>,
+    /// Whose ballot carries each non-voting delegator's weight
+    /// (proposal_id -> delegator -> (voter, weight))
+    delegated_votes: BTreeMap<u32, BTreeMap<Account, (Account, u64)>>,
+    /// Next proposal ID
+    next_proposal_id: u32,
+    /// Number of proposals in `proposals` not yet closed or cancelled
+    live_proposals: u32,
+    /// Finalized proposals moved out of `proposals` (proposal_id -> record)
+    archived: BTreeMap<u32, ProposalRecord>,
+    /// Blocks after voting ends before a finalized proposal can be archived
+    archive_age: u64,
+    /// Minimum blocks between queueing a proposal and allowing execution
+    execution_delay: u64,
+    /// Blocks after the delay during which a queued proposal can be executed
+    grace_period: u64,
+    /// Accounts holding each role
+    roles: BTreeMap<Role, BTreeSet<Account>>,
+    /// Who may create proposals
+    submission_policy: SubmissionPolicy,
+    /// Contracts allowed to vote with their own balance
+    contract_voters: BTreeSet<ContractId>,
+    /// Keys acting jointly as the admin (None unless the admin is the committee)
+    committee: Option<Committee>,
+    /// Quadratic voting rounds by ID
+    rounds: BTreeMap<u32, Round>,
+    /// Next round ID
+    next_round_id: u32,
+    /// Voice credits each voter has spent in a round ((round_id, voter) -> credits)
+    credits_spent: BTreeMap<(u32, Account), u64>,
+    /// Council elections by ID
+    elections: BTreeMap<u32, Election>,
+    /// Next election ID
+    next_election_id: u32,
+    /// Ranked ballots per election (election_id -> voter -> ballot)
+    election_ballots: BTreeMap<u32, BTreeMap<Account, RankedBallot>>,
+    /// Operations waiting for committee approvals (operation_id -> operation)
+    operations: BTreeMap<u32, PendingOperation>,
+    /// Next committee operation ID
+    next_operation_id: u32,
+    /// Whether an approved committee operation is being carried out
+    committee_executing: bool,
+    /// Whether voting, proposal creation and execution are halted
+    paused: bool,
+    /// Contract this state was exported to (the contract is read-only once set)
+    successor: Option<ContractId>,
+    /// Contract this state was imported from
+    predecessor: Option<ContractId>,
+}
+
+/// Contract state - persisted automatically by Piecrust
+#[cfg(target_family = "wasm")]
+static mut STATE: VoteContract = VoteContract {
+    state_version: STATE_VERSION,
+    admin: Account::Contract(ContractId::from_bytes([0u8; 32])),
+    pending_admin: None,
+    token_contract: ContractId::from_bytes([0u8; 32]),
+    voting_rules: VotingRules::DEFAULT,
+    voting_power_strategy: VotingPowerStrategy::DEFAULT,
+    proposals: BTreeMap::new(),
+    votes: BTreeMap::new(),
+    delegates: BTreeMap::new(),
+    delegators: BTreeMap::new(),
+    delegated_votes: BTreeMap::new(),
+    next_proposal_id: 0,
+    live_proposals: 0,
+    archived: BTreeMap::new(),
+    archive_age: DEFAULT_ARCHIVE_AGE,
+    execution_delay: DEFAULT_EXECUTION_DELAY,
+    grace_period: DEFAULT_GRACE_PERIOD,
+    roles: BTreeMap::new(),
+    submission_policy: SubmissionPolicy::ADMIN_ONLY,
+    contract_voters: BTreeSet::new(),
+    committee: None,
+    rounds: BTreeMap::new(),
+    next_round_id: 0,
+    credits_spent: BTreeMap::new(),
+    elections: BTreeMap::new(),
+    next_election_id: 0,
+    election_ballots: BTreeMap::new(),
+    operations: BTreeMap::new(),
+    next_operation_id: 0,
+    committee_executing: false,
+    paused: false,
+    successor: None,
+    predecessor: None,
+};
+
+/// Query token balance for an account
+#[cfg(target_family = "wasm")]
+fn get_token_balance(token_contract: ContractId, account: &Account) -> u64 {
+    // Call token contract's balance_of_account function
+    match abi::call(token_contract, "balance_of_account", account) {
+        Ok(balance) => balance,
+        Err(_) => 0, // Return 0 if call fails
+    }
+}
+
+/// Mock implementation for tests (non-WASM)
+#[cfg(not(target_family = "wasm"))]
+fn get_token_balance(_token_contract: ContractId, _account: &Account) -> u64 {
+    0 // Return 0 in test mode - tests should test contract state directly
+}
+
+/// Query token balance for an account as of the end of a past block
+#[cfg(target_family = "wasm")]
+fn get_token_balance_at(token_contract: ContractId, account: &Account, height: u64) -> u64 {
+    // Call token contract's checkpointed balance_of_account_at function
+    match abi::call(token_contract, "balance_of_account_at", &(*account, height)) {
+        Ok(balance) => balance,
+        Err(_) => 0, // Return 0 if call fails
+    }
+}
+
+/// Mock implementation for tests (non-WASM)
+#[cfg(not(target_family = "wasm"))]
+fn get_token_balance_at(_token_contract: ContractId, _account: &Account, _height: u64) -> u64 {
+    0 // Return 0 in test mode - tests should test contract state directly
+}
+
+/// Query the amount a key has staked with the stake contract
+#[cfg(target_family = "wasm")]
+fn get_staked_amount(public_key: &PublicKey) -> u64 {
+    use dusk_core::stake::{StakeData, STAKE_CONTRACT};
+
+    match abi::call::<_, Option<StakeData>>(STAKE_CONTRACT, "get_stake", public_key) {
+        Ok(stake) => stake.and_then(|s| s.amount).map(|a| a.value).unwrap_or(0),
+        Err(_) => 0, // Return 0 if call fails
+    }
+}
+
+/// Mock implementation for tests (non-WASM)
+#[cfg(not(target_family = "wasm"))]
+fn get_staked_amount(_public_key: &PublicKey) -> u64 {
+    0 // Return 0 in test mode - tests should test contract state directly
+}
+
+/// Check whether an account is registered with a registry contract
+#[cfg(target_family = "wasm")]
+fn is_registered(registry: ContractId, account: &Account) -> bool {
+    abi::call(registry, "is_registered", account).unwrap_or(false)
+}
+
+/// Mock implementation for tests (non-WASM)
+#[cfg(not(target_family = "wasm"))]
+fn is_registered(_registry: ContractId, _account: &Account) -> bool {
+    false // Not registered in test mode - tests should test contract state directly
+}
+
+/// Call a contract with raw rkyv-encoded argument bytes
+#[cfg(target_family = "wasm")]
+fn call_raw(target: ContractId, function: &str, args: &[u8]) -> Result<Vec<u8>, String> {
+    use alloc::string::ToString;
+    abi::call_raw(target, function, args).map_err(|error| error.to_string())
+}
+
+/// Mock implementation for tests (non-WASM)
+#[cfg(not(target_family = "wasm"))]
+fn call_raw(_target: ContractId, _function: &str, _args: &[u8]) -> Result<Vec<u8>, String> {
+    Ok(Vec::new()) // Succeed in test mode - tests should test contract state directly
+}
+
+/// Take the DUSK deposit attached to the current call
+#[cfg(target_family = "wasm")]
+fn accept_deposit(value: u64) -> Result<(), VoteError> {
+    use dusk_core::transfer::TRANSFER_CONTRACT;
+    abi::call::<_, ()>(TRANSFER_CONTRACT, "deposit", &value)
+        .map_err(|_| VoteError::TransferFailed)
+}
+
+/// Mock implementation for tests (non-WASM)
+#[cfg(not(target_family = "wasm"))]
+fn accept_deposit(_value: u64) -> Result<(), VoteError> {
+    Ok(()) // Succeed in test mode - tests should test contract state directly
+}
+
+/// Send DUSK held by this contract to an external account
+#[cfg(target_family = "wasm")]
+fn transfer_to_account(account: PublicKey, value: u64) -> Result<(), VoteError> {
+    use dusk_core::transfer::{ContractToAccount, TRANSFER_CONTRACT};
+    abi::call::<_, ()>(TRANSFER_CONTRACT, "contract_to_account", &ContractToAccount {
+        account,
+        value,
+    })
+    .map_err(|_| VoteError::TransferFailed)
+}
+
+/// Mock implementation for tests (non-WASM)
+#[cfg(not(target_family = "wasm"))]
+fn transfer_to_account(_account: PublicKey, _value: u64) -> Result<(), VoteError> {
+    Ok(()) // Succeed in test mode - tests should test contract state directly
+}
+
+/// Flatten per-proposal maps into lists for a state snapshot
+fn flatten_by_proposal<K: Copy, V: Clone>(
+    map: &BTreeMap<u32, BTreeMap<K, V>>,
+) -> ProposalEntries<K, V> {
+    map.iter()
+        .map(|(id, entries)| (*id, entries.iter().map(|(k, v)| (*k, v.clone())).collect()))
+        .collect()
+}
+
+/// Rebuild per-proposal maps from the lists of a state snapshot
+fn nest_by_proposal<K: Ord, V>(entries: ProposalEntries<K, V>) -> BTreeMap<u32, BTreeMap<K, V>> {
+    entries.into_iter().map(|(id, list)| (id, list.into_iter().collect())).collect()
+}
+
+/// Check that a snapshot exported at `version` can be imported
+/// A layout change keeps the previous snapshot type so it can still be converted;
+/// older states have to migrate through an intermediate deployment first
+fn check_snapshot_version(version: u32) -> Result<(), VoteError> {
+    if version < STATE_VERSION - 1 {
+        return Err(VoteError::OutdatedStateVersion);
+    }
+    if version > STATE_VERSION {
+        return Err(VoteError::UnsupportedStateVersion);
+    }
+    Ok(())
+}
+
+/// Ask a frozen contract for its state snapshot, decoded in the layout of its version
+#[cfg(target_family = "wasm")]
+fn fetch_snapshot(source: ContractId) -> Result<StateSnapshot, VoteError> {
+    let version = abi::call::<_, u32>(source, "state_version", &())
+        .map_err(|_| VoteError::MigrationFailed)?;
+    check_snapshot_version(version)?;
+    let snapshot = if version == STATE_VERSION {
+        abi::call::<_, Result<StateSnapshot, VoteError>>(source, "export_state", &())
+    } else {
+        abi::call::<_, Result<StateSnapshotV4, VoteError>>(source, "export_state", &())
+            .map(|snapshot| snapshot.map(StateSnapshot::from))
+    };
+    snapshot.map_err(|_| VoteError::MigrationFailed)?
+}
+
+/// Mock implementation for tests (non-WASM)
+#[cfg(not(target_family = "wasm"))]
+fn fetch_snapshot(_source: ContractId) -> Result<StateSnapshot, VoteError> {
+    Err(VoteError::MigrationFailed) // No other contracts in test mode - tests import directly
+}
+
+/// Hash bytes with the host's hash function
+#[cfg(target_family = "wasm")]
+fn hash(bytes: Vec<u8>) -> [u8; 32] {
+    abi::hash(bytes)
+}
+
+/// Mock implementation for tests (non-WASM)
+#[cfg(not(target_family = "wasm"))]
+fn hash(_bytes: Vec<u8>) -> [u8; 32] {
+    [0u8; 32] // Return zeroes in test mode - tests should test contract state directly
+}
+
+/// Commit to a proposal's ballots so the freed vote set can still be proven
+fn votes_commitment(votes: &BTreeMap<Account, Ballot>) -> [u8; 32] {
+    let mut bytes = Vec::new();
+    for (account, ballot) in votes {
+        match account {
+            Account::External(pk) => {
+                bytes.push(0);
+                bytes.extend_from_slice(&pk.to_raw_bytes());
+            }
+            Account::Contract(id) => {
+                bytes.push(1);
+                bytes.extend_from_slice(id.as_bytes());
+            }
+        }
+        match ballot.choice {
+            Choice::Standard(option) => bytes.extend_from_slice(&[0, option as u8]),
+            Choice::MultiChoice(index) => {
+                bytes.push(1);
+                bytes.extend_from_slice(&index.to_le_bytes());
+            }
+        }
+        bytes.extend_from_slice(&ballot.weight.to_le_bytes());
+    }
+    hash(bytes)
+}
+
+/// Query the token's total supply as of the end of a past block
+#[cfg(target_family = "wasm")]
+fn get_total_supply_at(token_contract: ContractId, height: u64) -> u64 {
+    match abi::call(token_contract, "total_supply_at", &height) {
+        Ok(supply) => supply,
+        Err(_) => 0, // Return 0 if call fails
+    }
+}
+
+/// Mock implementation for tests (non-WASM)
+#[cfg(not(target_family = "wasm"))]
+fn get_total_supply_at(_token_contract: ContractId, _height: u64) -> u64 {
+    0 // Return 0 in test mode - tests should test contract state directly
+}
+
+/// Total voting power available on a proposal, from the supply of the tokens it counts
+/// Strategies without a supply (see `VotingPowerStrategy::has_supply`) have none
+fn total_power_at(token_contract: ContractId, proposal: &Proposal) -> u64 {
+    let height = proposal.snapshot_height;
+    match &proposal.strategy.source {
+        PowerSource::TokenBalance | PowerSource::SnapshotBalance => {
+            get_total_supply_at(token_contract, height)
+        }
+        PowerSource::MultiToken(tokens) => tokens.iter().fold(0u64, |sum, t| {
+            sum.saturating_add(get_total_supply_at(t.token, height).saturating_mul(t.multiplier))
+        }),
+        PowerSource::Staked | PowerSource::Registry(_) => 0,
+    }
+}
+
+/// Evaluate a proposal's current tally against its rules
+/// Resolves a supply-based quorum at the proposal's snapshot height, taking its
+/// square root for round proposals
+/// Multi-choice proposals pass when quorum is met and one option leads outright
+fn evaluate_proposal(token_contract: ContractId, proposal: &Proposal) -> ProposalOutcome {
+    let quorum_weight = match proposal.rules.quorum {
+        Quorum::Absolute(weight) => weight,
+        Quorum::SupplyBps(bps) => {
+            let supply = total_power_at(token_contract, proposal);
+            let weight = (supply as u128 * bps as u128 / BASIS_POINTS as u128) as u64;
+            // Round tallies are in votes while the supply funds credits, so the
+            // quorum is the votes that share of the supply buys
+            if proposal.round.is_some() {
+                quadratic_votes(weight)
+            } else {
+                weight
+            }
+        }
+    };
+    if proposal.is_multi_choice() {
+        let participation = proposal
+            .option_votes
+            .iter()
+            .fold(0u64, |sum, votes| sum.saturating_add(*votes));
+        return if participation < quorum_weight {
+            ProposalOutcome::QuorumNotMet
+        } else if plurality_winner(&proposal.option_votes).is_some() {
+            ProposalOutcome::Passed
+        } else {
+            ProposalOutcome::Rejected
+        };
+    }
+    proposal.rules.evaluate(
+        proposal.yes_votes,
+        proposal.no_votes,
+        proposal.abstain_votes,
+        proposal.no_with_veto_votes,
+        quorum_weight,
+    )
+}
+
+impl VoteContract {
+    /// Initialize the contract with specified admin and token contract
+    /// The admin starts with every role
+    pub fn init(&mut self, admin: Account, token_contract: ContractId) {
+        self.admin = admin;
+        self.token_contract = token_contract;
+        self.pending_admin = None;
+        self.voting_rules = VotingRules::DEFAULT;
+        self.voting_power_strategy = VotingPowerStrategy::DEFAULT;
+        self.next_proposal_id = 0;
+        self.live_proposals = 0;
+        self.archive_age = DEFAULT_ARCHIVE_AGE;
+        self.execution_delay = DEFAULT_EXECUTION_DELAY;
+        self.grace_period = DEFAULT_GRACE_PERIOD;
+        self.roles = Role::ALL.iter().map(|role| (*role, BTreeSet::from([admin]))).collect();
+        self.submission_policy = SubmissionPolicy::ADMIN_ONLY;
+        self.contract_voters = BTreeSet::new();
+        self.committee = None;
+        self.rounds = BTreeMap::new();
+        self.next_round_id = 0;
+        self.credits_spent = BTreeMap::new();
+        self.elections = BTreeMap::new();
+        self.next_election_id = 0;
+        self.election_ballots = BTreeMap::new();
+        self.operations = BTreeMap::new();
+        self.next_operation_id = 0;
+        self.committee_executing = false;
+        self.paused = false;
+        self.state_version = STATE_VERSION;
+        self.successor = None;
+        self.predecessor = None;
+    }
+
+    /// Get the account the current call acts as
+    /// This is the committee while it carries out an approved operation, and the sender otherwise
+    /// Operations are approved by member keys, so a contract calling back in while one runs
+    /// (such as a token queried for its supply) is refused instead of acting as the committee
+    fn caller(&self) -> Result<Account, VoteError> {
+        let sender = sender_account()?;
+        if self.committee_executing {
+            return match sender {
+                Account::External(_) => Ok(committee_account()),
+                Account::Contract(_) => Err(VoteError::ReentrantCall),
+            };
+        }
+        Ok(sender)
+    }
+
+    /// Check that the contract has not been frozen for migration
+    fn ensure_not_frozen(&self) -> Result<(), VoteError> {
+        if self.successor.is_some() {
+            return Err(VoteError::Frozen);
+        }
+        Ok(())
+    }
+
+    /// Check that the caller is the admin
+    fn ensure_admin(&self) -> Result<(), VoteError> {
+        self.ensure_not_frozen()?;
+        if self.caller()? != self.admin {
+            return Err(VoteError::NotAdmin);
+        }
+        Ok(())
+    }
+
+    /// Check that the caller holds a role
+    fn ensure_role(&self, role: Role) -> Result<(), VoteError> {
+        self.ensure_not_frozen()?;
+        if !self.has_role(role, self.caller()?) {
+            return Err(VoteError::MissingRole);
+        }
+        Ok(())
+    }
+
+    /// Check that the contract is not paused
+    fn ensure_not_paused(&self) -> Result<(), VoteError> {
+        self.ensure_not_frozen()?;
+        if self.paused {
+            return Err(VoteError::Paused);
+        }
+        Ok(())
+    }
+
+    /// Find a proposal by ID
+    fn proposal(&self, proposal_id: u32) -> Result<&Proposal, VoteError> {
+        self.proposals.get(&proposal_id).ok_or(VoteError::ProposalNotFound)
+    }
+
+    /// Find a proposal by ID for modification
+    fn proposal_mut(&mut self, proposal_id: u32) -> Result<&mut Proposal, VoteError> {
+        self.proposals.get_mut(&proposal_id).ok_or(VoteError::ProposalNotFound)
+    }
+
+    // ==================== Admin Transfer Functions (Two-Step Process) ====================
+
+    /// Propose a new admin (admin only)
+    /// The new admin must call accept_admin() to complete the transfer
+    /// Only the admin role passes; roles held by the old admin are kept until revoked
+    pub fn propose_admin(&mut self, new_admin: Account) -> Result<(), VoteError> {
+        self.ensure_admin()?;
+        if new_admin == self.admin {
+            return Err(VoteError::CannotProposeSelf);
+        }
+        self.pending_admin = Some(new_admin);
+        emit(AdminProposed::TOPIC, AdminProposed { admin: self.admin, pending_admin: new_admin });
+        Ok(())
+    }
+
+    /// Accept admin role (pending admin only)
+    /// Completes the two-step admin transfer process
+    pub fn accept_admin(&mut self) -> Result<(), VoteError> {
+        self.ensure_not_frozen()?;
+        let caller = sender_account()?;
+        let pending = self.pending_admin.ok_or(VoteError::NoPendingTransfer)?;
+        if pending != caller {
+            return Err(VoteError::NotPendingAdmin);
+        }
+        let previous_admin = self.admin;
+        self.admin = caller;
+        self.pending_admin = None;
+        if previous_admin == committee_account() {
+            self.committee = None;
+            self.operations.clear();
+        }
+        emit(AdminAccepted::TOPIC, AdminAccepted { previous_admin, admin: caller });
+        Ok(())
+    }
+
+    /// Cancel pending admin transfer (admin only)
+    pub fn cancel_admin_proposal(&mut self) -> Result<(), VoteError> {
+        self.ensure_admin()?;
+        let pending_admin = self.pending_admin.take().ok_or(VoteError::NoPendingTransfer)?;
+        emit(
+            AdminTransferCancelled::TOPIC,
+            AdminTransferCancelled { admin: self.admin, pending_admin },
+        );
+        Ok(())
+    }
+
+    /// Get the pending admin (if any)
+    pub fn pending_admin(&self) -> Option<Account> {
+        self.pending_admin
+    }
+
+    // ==================== Committee Functions ====================
+
+    /// Make a committee the admin, or replace the current committee (admin only)
+    /// The previous admin's roles move to the committee and pending operations are dropped
+    pub fn set_committee(&mut self, committee: Committee) -> Result<(), VoteError> {
+        self.ensure_admin()?;
+        committee.validate()?;
+        let account = committee_account();
+        let previous_admin = self.admin;
+        if previous_admin != account {
+            for (role, members) in self.roles.iter_mut() {
+                if members.remove(&previous_admin) {
+                    members.insert(account);
+                    let role = *role;
+                    emit(RoleChanged::REVOKED_TOPIC, RoleChanged { role, account: previous_admin });
+                    emit(RoleChanged::GRANTED_TOPIC, RoleChanged { role, account });
+                }
+            }
+            self.admin = account;
+            self.pending_admin = None;
+            emit(AdminAccepted::TOPIC, AdminAccepted { previous_admin, admin: account });
+        }
+        self.committee = Some(committee.clone());
+        self.operations.clear();
+        emit(CommitteeUpdated::TOPIC, CommitteeUpdated { committee });
+        Ok(())
+    }
+
+    /// Get the caller's key if it is on the committee
+    fn committee_member(&self) -> Result<PublicKey, VoteError> {
+        self.ensure_not_frozen()?;
+        let committee = self.committee.as_ref().ok_or(VoteError::NotCommitteeMember)?;
+        match sender_account()? {
+            Account::External(pk) if committee.members.contains(&pk) => Ok(pk),
+            _ => Err(VoteError::NotCommitteeMember),
+        }
+    }
+
+    /// Submit an admin operation for committee approval (committee members only)
+    /// Submitting counts as the first approval
+    pub fn submit_operation(&mut self, operation: AdminOperation) -> Result<u32, VoteError> {
+        let submitter = self.committee_member()?;
+        let id = self.next_operation_id;
+        let pending = PendingOperation { id, operation, submitter, approvals: Vec::new() };
+        self.record_approval(pending, submitter, OperationApproved::SUBMITTED_TOPIC)?;
+        self.next_operation_id += 1;
+        Ok(id)
+    }
+
+    /// Approve a pending operation (committee members only)
+    /// Returns whether this approval reached the threshold and carried out the operation
+    pub fn approve_operation(&mut self, operation_id: u32) -> Result<bool, VoteError> {
+        let member = self.committee_member()?;
+        let pending =
+            self.operations.get(&operation_id).cloned().ok_or(VoteError::OperationNotFound)?;
+        if pending.approvals.contains(&member) {
+            return Err(VoteError::AlreadyApproved);
+        }
+        self.record_approval(pending, member, OperationApproved::TOPIC)
+    }
+
+    /// Withdraw a pending operation (its submitter only)
+    pub fn cancel_operation(&mut self, operation_id: u32) -> Result<(), VoteError> {
+        let member = self.committee_member()?;
+        let pending = self.operations.get(&operation_id).ok_or(VoteError::OperationNotFound)?;
+        if pending.submitter != member {
+            return Err(VoteError::NotSubmitter);
+        }
+        self.operations.remove(&operation_id);
+        emit(OperationClosed::CANCELLED_TOPIC, OperationClosed { operation_id });
+        Ok(())
+    }
+
+    /// Add a member's approval, carrying out the operation once the threshold is reached
+    /// A failing operation is left pending without the approval
+    fn record_approval(
+        &mut self,
+        mut pending: PendingOperation,
+        member: PublicKey,
+        topic: &'static str,
+    ) -> Result<bool, VoteError> {
+        let threshold = self.committee.as_ref().map_or(0, |committee| committee.threshold);
+        let operation_id = pending.id;
+        pending.approvals.push(member);
+        let approvals = pending.approvals.len() as u32;
+        let executed = approvals >= threshold;
+        if executed {
+            self.perform(pending.operation)?;
+            self.operations.remove(&operation_id);
+        } else {
+            self.operations.insert(operation_id, pending);
+        }
+
+        emit(topic, OperationApproved { operation_id, member, approvals });
+        if executed {
+            emit(OperationClosed::EXECUTED_TOPIC, OperationClosed { operation_id });
+        }
+        Ok(executed)
+    }
+
+    /// Carry out an approved operation as the committee
+    fn perform(&mut self, operation: AdminOperation) -> Result<(), VoteError> {
+        self.committee_executing = true;
+        let result = match operation {
+            AdminOperation::AddProposal(params) => self.add_proposal(params).map(|_| ()),
+            AdminOperation::CloseProposal(id) => self.close_proposal(id).map(|_| ()),
+            AdminOperation::CancelProposal(id) => self.cancel_proposal(id),
+            AdminOperation::CancelQueued(id) => self.cancel_queued(id),
+            AdminOperation::ProposeAdmin(account) => self.propose_admin(account),
+            AdminOperation::CancelAdminProposal => self.cancel_admin_proposal(),
+            AdminOperation::GrantRole(role, account) => self.grant_role(role, account),
+            AdminOperation::RevokeRole(role, account) => self.revoke_role(role, account),
+            AdminOperation::SetVotingRules(rules) => self.set_voting_rules(rules),
+            AdminOperation::SetVotingPowerStrategy(strategy) => {
+                self.set_voting_power_strategy(strategy)
+            }
+            AdminOperation::CreateRound(params) => self.create_round(params).map(|_| ()),
+            AdminOperation::CreateElection(params) => self.create_election(params).map(|_| ()),
+            AdminOperation::FinalizeElection(id) => self.finalize_election(id).map(|_| ()),
+            AdminOperation::SetArchiveAge(age) => self.set_archive_age(age),
+            AdminOperation::SetTimelock(delay, grace) => self.set_timelock(delay, grace),
+            AdminOperation::SetSubmissionPolicy(policy) => self.set_submission_policy(policy),
+            AdminOperation::AllowContractVoter(contract) => self.allow_contract_voter(contract),
+            AdminOperation::RemoveContractVoter(contract) => self.remove_contract_voter(contract),
+            AdminOperation::SetCommittee(committee) => self.set_committee(committee),
+            AdminOperation::Pause(reason) => self.pause(reason),
+            AdminOperation::Unpause(reason) => self.unpause(reason),
+            AdminOperation::Freeze(successor) => self.freeze(successor),
+            AdminOperation::MigrateFrom(source) => self.migrate_from(source),
+        };
+        self.committee_executing = false;
+        result
+    }
+
+    // ==================== Pause Functions ====================
+
+    /// Halt voting, proposal creation and execution (admin or Pauser only)
+    pub fn pause(&mut self, reason: String) -> Result<(), VoteError> {
+        let account = self.ensure_pauser(&reason)?;
+        if self.paused {
+            return Err(VoteError::Paused);
+        }
+        self.paused = true;
+        emit(PauseChanged::PAUSED_TOPIC, PauseChanged { account, reason });
+        Ok(())
+    }
+
+    /// Resume normal operation (admin or Pauser only)
+    pub fn unpause(&mut self, reason: String) -> Result<(), VoteError> {
+        let account = self.ensure_pauser(&reason)?;
+        if !self.paused {
+            return Err(VoteError::NotPaused);
+        }
+        self.paused = false;
+        emit(PauseChanged::UNPAUSED_TOPIC, PauseChanged { account, reason });
+        Ok(())
+    }
+
+    /// Check that the caller may pause or unpause with this reason
+    /// Returns the caller
+    fn ensure_pauser(&self, reason: &str) -> Result<Account, VoteError> {
+        self.ensure_not_frozen()?;
+        let account = self.caller()?;
+        if account != self.admin && !self.has_role(Role::Pauser, account) {
+            return Err(VoteError::MissingRole);
+        }
+        if reason.len() > MAX_REASON_LEN {
+            return Err(VoteError::ReasonTooLong);
+        }
+        Ok(account)
+    }
+
+    // ==================== Role Functions ====================
+
+    /// Grant a role to an account (admin only)
+    pub fn grant_role(&mut self, role: Role, account: Account) -> Result<(), VoteError> {
+        self.ensure_admin()?;
+        if self.roles.entry(role).or_default().insert(account) {
+            emit(RoleChanged::GRANTED_TOPIC, RoleChanged { role, account });
+        }
+        Ok(())
+    }
+
+    /// Revoke a role from an account (admin only)
+    pub fn revoke_role(&mut self, role: Role, account: Account) -> Result<(), VoteError> {
+        self.ensure_admin()?;
+        let members = self.roles.get_mut(&role).ok_or(VoteError::MissingRole)?;
+        if !members.remove(&account) {
+            return Err(VoteError::MissingRole);
+        }
+        if members.is_empty() {
+            self.roles.remove(&role);
+        }
+        emit(RoleChanged::REVOKED_TOPIC, RoleChanged { role, account });
+        Ok(())
+    }
+
+    /// Set the rules applied to proposals created without their own (ConfigManager only)
+    /// Existing proposals keep the rules they were created with
+    pub fn set_voting_rules(&mut self, rules: VotingRules) -> Result<(), VoteError> {
+        self.ensure_role(Role::ConfigManager)?;
+        rules.validate()?;
+        self.voting_rules = rules;
+        emit(VotingRulesUpdated::TOPIC, VotingRulesUpdated { rules });
+        Ok(())
+    }
+
+    /// Set the voting power strategy for proposals created without their own (ConfigManager only)
+    /// Existing proposals keep the strategy they were created with
+    pub fn set_voting_power_strategy(
+        &mut self,
+        strategy: VotingPowerStrategy,
+    ) -> Result<(), VoteError> {
+        self.ensure_role(Role::ConfigManager)?;
+        strategy.validate()?;
+        self.voting_power_strategy = strategy.clone();
+        emit(VotingPowerStrategyUpdated::TOPIC, VotingPowerStrategyUpdated { strategy });
+        Ok(())
+    }
+
+    /// Set how many blocks after voting ends a finalized proposal is kept live (ConfigManager only)
+    pub fn set_archive_age(&mut self, archive_age: u64) -> Result<(), VoteError> {
+        self.ensure_role(Role::ConfigManager)?;
+        self.archive_age = archive_age;
+        emit(ArchiveAgeUpdated::TOPIC, ArchiveAgeUpdated { archive_age });
+        Ok(())
+    }
+
+    /// Set the timelock delay and grace period applied when queueing (ConfigManager only)
+    /// Proposals already queued keep the heights they were given
+    pub fn set_timelock(
+        &mut self,
+        execution_delay: u64,
+        grace_period: u64,
+    ) -> Result<(), VoteError> {
+        self.ensure_role(Role::ConfigManager)?;
+        self.execution_delay = execution_delay;
+        self.grace_period = grace_period;
+        emit(TimelockUpdated::TOPIC, TimelockUpdated { execution_delay, grace_period });
+        Ok(())
+    }
+
+    /// Set who may create proposals (ConfigManager only)
+    /// A minimum deposit needs a treasury to receive slashed deposits
+    pub fn set_submission_policy(&mut self, policy: SubmissionPolicy) -> Result<(), VoteError> {
+        self.ensure_role(Role::ConfigManager)?;
+        if policy.min_deposit > 0 && policy.treasury.is_none() {
+            return Err(VoteError::DepositNotAccepted);
+        }
+        self.submission_policy = policy;
+        emit(SubmissionPolicyUpdated::TOPIC, SubmissionPolicyUpdated { policy });
+        Ok(())
+    }
+
+    /// Let a contract vote with its own balance (ConfigManager only)
+    /// Only allowlist contracts whose balance can't be borrowed around a snapshot
+    pub fn allow_contract_voter(&mut self, contract: ContractId) -> Result<(), VoteError> {
+        self.ensure_role(Role::ConfigManager)?;
+        if self.contract_voters.insert(contract) {
+            emit(ContractVoterChanged::ALLOWED_TOPIC, ContractVoterChanged { contract });
+        }
+        Ok(())
+    }
+
+    /// Take a contract off the voter allowlist (ConfigManager only)
+    /// Ballots it already cast are kept
+    pub fn remove_contract_voter(&mut self, contract: ContractId) -> Result<(), VoteError> {
+        self.ensure_role(Role::ConfigManager)?;
+        if !self.contract_voters.remove(&contract) {
+            return Err(VoteError::NotContractVoter);
+        }
+        emit(ContractVoterChanged::REMOVED_TOPIC, ContractVoterChanged { contract });
+        Ok(())
+    }
+
+    /// Check that the caller may create the proposal
+    /// Creators without the Proposer role must use the global rules and strategy and
+    /// cannot attach actions, which run with the vote contract's authority
+    /// Returns the creator
+    fn ensure_can_propose(&self, params: &NewProposal) -> Result<Account, VoteError> {
+        let deposit = params.deposit;
+        let creator = self.caller()?;
+        let policy = &self.submission_policy;
+        if !self.has_role(Role::Proposer, creator) {
+            if !policy.permissionless {
+                return Err(VoteError::MissingRole);
+            }
+            if let Account::Contract(_) = creator {
+                return Err(VoteError::ContractsCannotPropose);
+            }
+            if params.rules.is_some() || params.strategy.is_some() || !params.actions.is_empty() {
+                return Err(VoteError::ProposerOnly);
+            }
+            if deposit < policy.min_deposit {
+                return Err(VoteError::DepositTooLow);
+            }
+            if get_token_balance(self.token_contract, &creator) <= policy.threshold {
+                return Err(VoteError::BelowProposalThreshold);
+            }
+        }
+        if deposit > 0
+            && (policy.treasury.is_none() || !matches!(creator, Account::External(_)))
+        {
+            return Err(VoteError::DepositNotAccepted);
+        }
+        Ok(creator)
+    }
+
+    /// Return a proposal's deposit to its creator, or send it to the treasury
+    /// Deposits are refunded if the treasury has since been removed
+    /// Called before the proposal is finalized, so a failed transfer leaves it unchanged
+    fn settle_deposit(&self, proposal_id: u32, refund: bool) -> Result<(), VoteError> {
+        let proposal = self.proposal(proposal_id)?;
+        let Account::External(creator) = proposal.creator else {
+            return Ok(());
+        };
+        if proposal.deposit == 0 {
+            return Ok(());
+        }
+        let (topic, recipient) = match self.submission_policy.treasury {
+            Some(treasury) if !refund => (DepositSettled::SLASHED_TOPIC, treasury),
+            _ => (DepositSettled::REFUNDED_TOPIC, creator),
+        };
+        transfer_to_account(recipient, proposal.deposit)?;
+        emit(topic, DepositSettled { proposal_id, recipient, amount: proposal.deposit });
+        Ok(())
+    }
+
+    // ==================== Proposal Functions ====================
+
+    /// Add a new proposal
+    /// Only Proposers can create proposals unless the submission policy is permissionless
+    /// Caller is determined from the call stack
+    /// A non-empty `options` list makes it a multi-choice proposal
+    /// Actions may not target this contract, since those calls would run as the committee
+    /// A round proposal must be yes/no, share the round's voting window and join before it starts
+    pub fn add_proposal(&mut self, params: NewProposal) -> Result<u32, VoteError> {
+        self.ensure_not_paused()?;
+        let creator = self.ensure_can_propose(&params)?;
+        let NewProposal {
+            description,
+            start_height,
+            end_height,
+            rules,
+            strategy,
+            options,
+            category,
+            actions,
+            deposit,
+            round,
+        } = params;
+        if self.live_proposals as usize >= MAX_PROPOSALS {
+            return Err(VoteError::MaxProposalsReached);
+        }
+        if description.len() > MAX_PROPOSAL_DESC_LEN {
+            return Err(VoteError::DescriptionTooLong);
+        }
+        if !options.is_empty() && !(2..=MAX_PROPOSAL_OPTIONS).contains(&options.len()) {
+            return Err(VoteError::InvalidOptionCount);
+        }
+        if options.iter().any(|label| label.len() > MAX_OPTION_LABEL_LEN) {
+            return Err(VoteError::OptionLabelTooLong);
+        }
+        if category.len() > MAX_CATEGORY_LEN {
+            return Err(VoteError::CategoryTooLong);
+        }
+        if actions.len() > MAX_ACTIONS {
+            return Err(VoteError::TooManyActions);
+        }
+        if actions.iter().any(|action| {
+            action.function.len() > MAX_FUNCTION_NAME_LEN || action.args.len() > MAX_ACTION_ARGS_LEN
+        }) {
+            return Err(VoteError::ActionTooLarge);
+        }
+        // Calls to this contract would run as the committee, which holds every role
+        if actions.iter().any(|action| Account::Contract(action.target) == committee_account()) {
+            return Err(VoteError::SelfTargetingAction);
+        }
+        if start_height >= end_height {
+            return Err(VoteError::InvalidVotingWindow);
+        }
+        if end_height <= block_height() {
+            return Err(VoteError::VotingWindowInPast);
+        }
+        let rules = rules.unwrap_or(self.voting_rules);
+        rules.validate()?;
+        // Voice credits are always read with the default strategy, so quorum for
+        // round proposals is measured against that one
+        let strategy = match (round, strategy) {
+            (Some(_), Some(_)) => return Err(VoteError::InvalidStrategy),
+            (Some(_), None) => VotingPowerStrategy::DEFAULT,
+            (None, strategy) => strategy.unwrap_or_else(|| self.voting_power_strategy.clone()),
+        };
+        strategy.validate()?;
+        if matches!(rules.quorum, Quorum::SupplyBps(_)) && !strategy.has_supply() {
+            return Err(VoteError::QuorumNeedsSupply);
+        }
+        if let Some(round_id) = round {
+            let round = self.rounds.get(&round_id).ok_or(VoteError::RoundNotFound)?;
+            if block_height() >= round.start_height {
+                return Err(VoteError::RoundStarted);
+            }
+            if (start_height, end_height) != (round.start_height, round.end_height) {
+                return Err(VoteError::InvalidVotingWindow);
+            }
+            if !options.is_empty() {
+                return Err(VoteError::RoundProposal);
+            }
+            if round.proposal_ids.len() >= MAX_ROUND_PROPOSALS {
+                return Err(VoteError::RoundFull);
+            }
+        }
+        if deposit > 0 {
+            accept_deposit(deposit)?;
+        }
+
+        let id = self.next_proposal_id;
+        self.next_proposal_id += 1;
+        self.live_proposals += 1;
+
+        // Snapshot the last completed block, so balances moved within the
+        // creation block (or any later one) cannot add voting power.
+        // Round proposals share the round's snapshot, which funds the credits
+        let snapshot_height = match round.and_then(|id| self.rounds.get_mut(&id)) {
+            Some(round) => {
+                round.proposal_ids.push(id);
+                round.snapshot_height
+            }
+            None => block_height().saturating_sub(1),
+        };
+
+        emit(
+            ProposalCreated::TOPIC,
+            ProposalCreated {
+                proposal_id: id,
+                creator,
+                start_height,
+                end_height,
+                snapshot_height,
+                option_count: options.len() as u32,
+                category: category.clone(),
+            },
+        );
+        self.proposals.insert(id, Proposal {
+            id,
+            description,
+            yes_votes: 0,
+            no_votes: 0,
+            start_height,
+            end_height,
+            snapshot_height,
+            rules,
+            outcome: None,
+            status: ProposalStatus::Pending,
+            abstain_votes: 0,
+            no_with_veto_votes: 0,
+            option_votes: options.iter().map(|_| 0).collect(),
+            options,
+            winning_option: None,
+            category,
+            creator,
+            actions,
+            execute_after: 0,
+            action_results: Vec::new(),
+            expires_at: 0,
+            deposit,
+            strategy,
+            round,
+        });
+
+        self.votes.insert(id, BTreeMap::new());
+        Ok(id)
+    }
+
+    /// Close a proposal once voting has ended and record its outcome (Closer only)
+    /// Moves the proposal to Succeeded or Defeated
+    /// The deposit is refunded if quorum was met without a veto, and slashed otherwise
+    /// Caller is determined from the call stack
+    pub fn close_proposal(&mut self, proposal_id: u32) -> Result<ProposalOutcome, VoteError> {
+        self.ensure_role(Role::Closer)?;
+        let height = block_height();
+        let token_contract = self.token_contract;
+        let proposal = self.proposal_mut(proposal_id)?;
+
+        match proposal.status {
+            ProposalStatus::Pending | ProposalStatus::Active => (),
+            ProposalStatus::Cancelled => return Err(VoteError::ProposalCancelled),
+            _ => return Err(VoteError::ProposalAlreadyFinalized),
+        }
+        if height < proposal.start_height {
+            return Err(VoteError::VotingNotStarted);
+        }
+        if height < proposal.end_height {
+            return Err(VoteError::VotingNotEnded);
+        }
+
+        let outcome = evaluate_proposal(token_contract, proposal);
+        let refund = matches!(outcome, ProposalOutcome::Passed | ProposalOutcome::Rejected);
+        self.settle_deposit(proposal_id, refund)?;
+
+        let proposal = self.proposal_mut(proposal_id)?;
+        proposal.status = match outcome {
+            ProposalOutcome::Passed => ProposalStatus::Succeeded,
+            _ => ProposalStatus::Defeated,
+        };
+        proposal.outcome = Some(outcome);
+        if outcome == ProposalOutcome::Passed && proposal.is_multi_choice() {
+            proposal.winning_option = plurality_winner(&proposal.option_votes);
+        }
+        emit(
+            ProposalClosed::TOPIC,
+            ProposalClosed { proposal_id, outcome, winning_option: proposal.winning_option },
+        );
+        self.live_proposals -= 1;
+        Ok(outcome)
+    }
+
+    /// Cancel a proposal that has not been finalized (Canceller only)
+    /// The proposal is treated as spam and its deposit is slashed
+    /// Caller is determined from the call stack
+    pub fn cancel_proposal(&mut self, proposal_id: u32) -> Result<(), VoteError> {
+        self.ensure_role(Role::Canceller)?;
+        match self.proposal(proposal_id)?.status {
+            ProposalStatus::Pending | ProposalStatus::Active => (),
+            ProposalStatus::Cancelled => return Err(VoteError::ProposalCancelled),
+            _ => return Err(VoteError::CannotCancelFinalized),
+        }
+        self.settle_deposit(proposal_id, false)?;
+        self.proposal_mut(proposal_id)?.status = ProposalStatus::Cancelled;
+        self.live_proposals -= 1;
+        emit(ProposalCancelled::TOPIC, ProposalCancelled { proposal_id });
+        Ok(())
+    }
+
+    // ==================== Timelock Functions ====================
+
+    /// Queue a succeeded proposal with actions, starting its timelock
+    /// Anyone may call this once the outcome has been recorded
+    pub fn queue(&mut self, proposal_id: u32) -> Result<(), VoteError> {
+        self.ensure_not_frozen()?;
+        let height = block_height();
+        let execute_after = height.saturating_add(self.execution_delay);
+        let expires_at = execute_after.saturating_add(self.grace_period);
+        let proposal = self.proposal_mut(proposal_id)?;
+        if proposal.status != ProposalStatus::Succeeded || proposal.actions.is_empty() {
+            return Err(VoteError::NotExecutable);
+        }
+
+        proposal.status = ProposalStatus::Queued;
+        proposal.execute_after = execute_after;
+        proposal.expires_at = expires_at;
+        emit(ProposalQueued::TOPIC, ProposalQueued { proposal_id, execute_after, expires_at });
+        Ok(())
+    }
+
+    /// Cancel a queued proposal before its timelock elapses (Canceller only)
+    /// Caller is determined from the call stack
+    pub fn cancel_queued(&mut self, proposal_id: u32) -> Result<(), VoteError> {
+        self.ensure_role(Role::Canceller)?;
+        let height = block_height();
+        let proposal = self.proposal_mut(proposal_id)?;
+        if proposal.status != ProposalStatus::Queued {
+            return Err(VoteError::NotQueued);
+        }
+        if height >= proposal.execute_after {
+            return Err(VoteError::TimelockElapsed);
+        }
+
+        proposal.status = ProposalStatus::Cancelled;
+        emit(ProposalCancelled::TOPIC, ProposalCancelled { proposal_id });
+        Ok(())
+    }
+
+    /// Perform a queued proposal's actions once its timelock has passed
+    /// Anyone may call this; the calls are made with the vote contract as caller
+    /// Actions run in order and execution stops at the first failing call
+    pub fn execute(&mut self, proposal_id: u32) -> Result<Vec<ActionResult>, VoteError> {
+        self.ensure_not_paused()?;
+        let height = block_height();
+        let proposal = self.proposal_mut(proposal_id)?;
+        match proposal.status {
+            ProposalStatus::Queued => (),
+            ProposalStatus::Executed => return Err(VoteError::AlreadyExecuted),
+            _ => return Err(VoteError::NotQueued),
+        }
+        if height < proposal.execute_after {
+            return Err(VoteError::TimelockNotElapsed);
+        }
+        if height >= proposal.expires_at {
+            return Err(VoteError::ProposalExpired);
+        }
+
+        // Mark the proposal executed before calling out, so a target
+        // calling back into this contract cannot execute it again
+        proposal.status = ProposalStatus::Executed;
+        let actions = proposal.actions.clone();
+
+        let mut results = Vec::with_capacity(actions.len());
+        for action in &actions {
+            match call_raw(action.target, &action.function, &action.args) {
+                Ok(data) => results.push(ActionResult::Success(data)),
+                Err(error) => {
+                    results.push(ActionResult::Failed(error));
+                    break;
+                }
+            }
+        }
+
+        self.proposal_mut(proposal_id)?.action_results = results.clone();
+        emit(ProposalExecuted::TOPIC, ProposalExecuted { proposal_id, results: results.clone() });
+        Ok(results)
+    }
+
+    // ==================== Voting Functions ====================
+
+    /// Vote yes or no on a proposal
+    /// Voter is determined from the call stack
+    /// - proposal_id: which proposal to vote on
+    /// - vote_yes: true for yes, false for no
+    pub fn vote(&mut self, proposal_id: u32, vote_yes: bool) -> Result<(), VoteError> {
+        let option = if vote_yes { VoteOption::Yes } else { VoteOption::No };
+        self.cast_vote(proposal_id, option)
+    }
+
+    /// Vote on a yes/no proposal with any ballot option
+    /// Voter is determined from the call stack
+    pub fn cast_vote(&mut self, proposal_id: u32, option: VoteOption) -> Result<(), VoteError> {
+        self.record_vote(proposal_id, Choice::Standard(option))
+    }
+
+    /// Vote for one option of a multi-choice proposal
+    /// Voter is determined from the call stack
+    /// - option_index: index into the proposal's `options`
+    pub fn vote_choice(&mut self, proposal_id: u32, option_index: u32) -> Result<(), VoteError> {
+        self.record_vote(proposal_id, Choice::MultiChoice(option_index))
+    }
+
+    /// Get the calling voter
+    /// Contracts can only vote, or change their vote, while on the allowlist
+    fn voter(&self) -> Result<Account, VoteError> {
+        let voter = sender_account()?;
+        if let Account::Contract(contract) = voter {
+            if !self.contract_voters.contains(&contract) {
+                return Err(VoteError::ContractsCannotVote);
+            }
+        }
+        Ok(voter)
+    }
+
+    /// Record the caller's ballot, weighted by their balance at the snapshot height
+    /// plus the balances of everyone delegating to them who has not voted
+    fn record_vote(&mut self, proposal_id: u32, choice: Choice) -> Result<(), VoteError> {
+        self.ensure_not_paused()?;
+        let voter = self.voter()?;
+
+        // Check proposal exists, its voting window is open and the choice fits it
+        let proposal = self.proposal(proposal_id)?;
+        proposal.check_voting_open(block_height())?;
+        proposal.check_choice(choice)?;
+        let strategy = proposal.strategy.clone();
+        let snapshot_height = proposal.snapshot_height;
+
+        // Check if already voted
+        if self.votes.get(&proposal_id).is_some_and(|v| v.contains_key(&voter)) {
+            return Err(VoteError::AlreadyVoted);
+        }
+
+        // Query balances at the proposal's snapshot height
+        let own_balance = self.voting_power_at(&strategy, &voter, snapshot_height);
+        let delegated: Vec<(Account, u64)> = self
+            .delegated_accounts(proposal_id, voter)
+            .into_iter()
+            .map(|account| (account, self.voting_power_at(&strategy, &account, snapshot_height)))
+            .collect();
+        let weight = delegated
+            .iter()
+            .try_fold(own_balance, |sum, (_, balance)| sum.checked_add(*balance))
+            .ok_or(VoteError::TallyOverflow)?;
+        if weight == 0 {
+            return Err(VoteError::NoVotingPower);
+        }
+        // Taking back delegated weight only lowers tallies, so this bounds the final one
+        let proposal = self.proposal(proposal_id)?;
+        proposal.tally(choice).checked_add(weight).ok_or(VoteError::TallyOverflow)?;
+
+        let proposal = self.proposals.get_mut(&proposal_id).ok_or(VoteError::ProposalNotFound)?;
+        let proposal_votes = self.votes.entry(proposal_id).or_default();
+        let delegated_votes = self.delegated_votes.entry(proposal_id).or_default();
+
+        // A direct vote overrides delegation, so take back any weight a
+        // delegate already cast on behalf of the voter or their delegators
+        let previous = delegated_votes.remove(&voter).into_iter().chain(
+            delegated.iter().filter_map(|(account, balance)| {
+                delegated_votes.insert(*account, (voter, *balance))
+            }),
+        );
+        for (representative, weight) in previous {
+            if let Some(ballot) = proposal_votes.get_mut(&representative) {
+                ballot.weight = ballot.weight.saturating_sub(weight);
+                let tally = proposal.tally_mut(ballot.choice);
+                *tally = tally.saturating_sub(weight);
+            }
+        }
+
+        *proposal.tally_mut(choice) += weight;
+        proposal_votes.insert(voter, Ballot { choice, weight });
+        emit(VoteCast::TOPIC, VoteCast { proposal_id, voter, choice: Some(choice), weight });
+        Ok(())
+    }
+
+    /// Query an account's own voting power under a strategy, for a snapshot height
+    /// Contracts off the allowlist carry no weight, so borrowed balances can't be parked in them
+    fn voting_power_at(
+        &self,
+        strategy: &VotingPowerStrategy,
+        account: &Account,
+        height: u64,
+    ) -> u64 {
+        if let Account::Contract(contract) = account {
+            if !self.contract_voters.contains(contract) {
+                return 0;
+            }
+        }
+        let power = match &strategy.source {
+            PowerSource::TokenBalance => get_token_balance(self.token_contract, account),
+            PowerSource::SnapshotBalance => {
+                get_token_balance_at(self.token_contract, account, height)
+            }
+            PowerSource::Staked => match account {
+                Account::External(pk) => get_staked_amount(pk),
+                Account::Contract(_) => 0,
+            },
+            PowerSource::MultiToken(tokens) => tokens.iter().fold(0u64, |sum, t| {
+                let balance = get_token_balance_at(t.token, account, height);
+                sum.saturating_add(balance.saturating_mul(t.multiplier))
+            }),
+            PowerSource::Registry(registry) => is_registered(*registry, account) as u64,
+        };
+        strategy.transform.apply(power)
+    }
+
+    /// Get every account whose weight would flow to `account` on a proposal
+    /// Follows delegation chains, stopping at accounts that voted themselves
+    fn delegated_accounts(&self, proposal_id: u32, account: Account) -> Vec<Account> {
+        let scopes = self.scope_chain(&DelegationScope::Proposal(proposal_id));
+        let votes = self.votes.get(&proposal_id);
+        let mut accounts = Vec::new();
+        let mut pending = Vec::from([account]);
+        while let Some(delegate) = pending.pop() {
+            for scope in &scopes {
+                let key = (delegate, scope.clone());
+                for delegator in self.delegators.get(&key).into_iter().flatten() {
+                    // Skip delegators overridden by a more specific delegation,
+                    // those who voted, and a chain leading back to `account`
+                    if *delegator == account
+                        || votes.is_some_and(|v| v.contains_key(delegator))
+                        || self.resolve_delegate(*delegator, &scopes) != Some(delegate)
+                    {
+                        continue;
+                    }
+                    accounts.push(*delegator);
+                    pending.push(*delegator);
+                }
+            }
+        }
+        accounts
+    }
+
+    /// Get the scopes consulted when resolving a delegation, most specific first
+    fn scope_chain(&self, scope: &DelegationScope) -> Vec<DelegationScope> {
+        let mut scopes = Vec::from([scope.clone()]);
+        if let DelegationScope::Proposal(id) = scope {
+            let category = self.proposals
+                .get(id)
+                .map(|p| &p.category)
+                .filter(|category| !category.is_empty());
+            if let Some(category) = category {
+                scopes.push(DelegationScope::Category(category.clone()));
+            }
+        }
+        if *scope != DelegationScope::Global {
+            scopes.push(DelegationScope::Global);
+        }
+        scopes
+    }
+
+    /// Get the scope chains where a delegation of `delegator` in `scope` would be followed
+    /// Only scopes someone delegates in can route a chain differently, so those are the
+    /// ones considered; chains where the delegator has a more specific delegation are skipped
+    fn chains_through(
+        &self,
+        delegator: Account,
+        scope: &DelegationScope,
+    ) -> Vec<Vec<DelegationScope>> {
+        let scopes: BTreeSet<DelegationScope> = self.delegates
+            .keys()
+            .map(|(_, delegated)| delegated.clone())
+            .chain([scope.clone()])
+            .collect();
+        scopes
+            .iter()
+            .map(|start| self.scope_chain(start))
+            .filter(|chain| match chain.iter().position(|s| s == scope) {
+                Some(position) => chain[..position]
+                    .iter()
+                    .all(|s| !self.delegates.contains_key(&(delegator, s.clone()))),
+                None => false,
+            })
+            .collect()
+    }
+
+    /// Get the delegate an account resolves to under the first matching scope
+    fn resolve_delegate(&self, account: Account, scopes: &[DelegationScope]) -> Option<Account> {
+        scopes
+            .iter()
+            .find_map(|scope| self.delegates.get(&(account, scope.clone())).copied())
+    }
+
+    /// Move the caller's recorded weight to a different choice
+    /// Voter is determined from the call stack
+    pub fn change_vote(&mut self, proposal_id: u32, choice: Choice) -> Result<(), VoteError> {
+        self.ensure_not_paused()?;
+        let voter = self.voter()?;
+
+        let proposal = self.proposals.get_mut(&proposal_id).ok_or(VoteError::ProposalNotFound)?;
+        proposal.check_voting_open(block_height())?;
+        proposal.check_choice(choice)?;
+
+        let ballot = self.votes.get_mut(&proposal_id)
+            .and_then(|v| v.get_mut(&voter))
+            .ok_or(VoteError::NotVoted)?;
+        if ballot.choice != choice {
+            proposal.tally(choice).checked_add(ballot.weight).ok_or(VoteError::TallyOverflow)?;
+        }
+
+        let tally = proposal.tally_mut(ballot.choice);
+        *tally = tally.saturating_sub(ballot.weight);
+        *proposal.tally_mut(choice) += ballot.weight;
+        ballot.choice = choice;
+        emit(
+            VoteCast::CHANGED_TOPIC,
+            VoteCast { proposal_id, voter, choice: Some(choice), weight: ballot.weight },
+        );
+        Ok(())
+    }
+
+    /// Withdraw the caller's vote, removing its weight from the tallies
+    /// Voter is determined from the call stack
+    pub fn retract_vote(&mut self, proposal_id: u32) -> Result<(), VoteError> {
+        self.ensure_not_paused()?;
+        let voter = sender_account()?;
+
+        let proposal = self.proposals.get_mut(&proposal_id).ok_or(VoteError::ProposalNotFound)?;
+        proposal.check_voting_open(block_height())?;
+        if proposal.round.is_some() {
+            return Err(VoteError::RoundProposal);
+        }
+
+        let ballot = self.votes.get_mut(&proposal_id)
+            .and_then(|v| v.remove(&voter))
+            .ok_or(VoteError::NotVoted)?;
+
+        let tally = proposal.tally_mut(ballot.choice);
+        *tally = tally.saturating_sub(ballot.weight);
+
+        // Delegated weight is no longer cast by anyone
+        if let Some(delegated_votes) = self.delegated_votes.get_mut(&proposal_id) {
+            delegated_votes.retain(|_, (representative, _)| *representative != voter);
+        }
+        emit(
+            VoteCast::RETRACTED_TOPIC,
+            VoteCast { proposal_id, voter, choice: None, weight: ballot.weight },
+        );
+        Ok(())
+    }
+
+    // ==================== Quadratic Voting Functions ====================
+
+    /// Create a quadratic voting round that proposals can join until it starts (Proposer only)
+    /// Voice credits are read at the last completed block, like a proposal snapshot
+    pub fn create_round(&mut self, params: NewRound) -> Result<u32, VoteError> {
+        let NewRound { description, start_height, end_height } = params;
+        self.ensure_not_paused()?;
+        self.ensure_role(Role::Proposer)?;
+        if description.len() > MAX_PROPOSAL_DESC_LEN {
+            return Err(VoteError::DescriptionTooLong);
+        }
+        if start_height >= end_height {
+            return Err(VoteError::InvalidVotingWindow);
+        }
+        if start_height <= block_height() {
+            return Err(VoteError::VotingWindowInPast);
+        }
+
+        let id = self.next_round_id;
+        self.next_round_id += 1;
+        let snapshot_height = block_height().saturating_sub(1);
+        self.rounds.insert(id, Round {
+            id,
+            description,
+            start_height,
+            end_height,
+            snapshot_height,
+            proposal_ids: Vec::new(),
+        });
+        emit(
+            RoundCreated::TOPIC,
+            RoundCreated { round_id: id, start_height, end_height, snapshot_height },
+        );
+        Ok(id)
+    }
+
+    /// Cast `votes` quadratic votes for or against a round proposal, costing `votes`² credits
+    /// Replaces the caller's earlier votes on the proposal, refunding their cost;
+    /// zero votes withdraws them. Delegation does not apply to rounds
+    /// Voter is determined from the call stack
+    pub fn vote_quadratic(
+        &mut self,
+        proposal_id: u32,
+        support: bool,
+        votes: u64,
+    ) -> Result<(), VoteError> {
+        self.ensure_not_paused()?;
+        let voter = sender_account()?;
+        if let Account::Contract(contract) = voter {
+            if !self.contract_voters.contains(&contract) {
+                return Err(VoteError::ContractsCannotVote);
+            }
+        }
+
+        let proposal = self.proposal(proposal_id)?;
+        proposal.check_voting_open(block_height())?;
+        let round_id = proposal.round.ok_or(VoteError::NotRoundProposal)?;
+        let round = self.rounds.get(&round_id).ok_or(VoteError::RoundNotFound)?;
+        let credits = self.round_credits(round, &voter);
+        if credits == 0 {
+            return Err(VoteError::NoVotingPower);
+        }
+
+        let previous = self.votes.get(&proposal_id).and_then(|v| v.get(&voter)).copied();
+        let refund = match previous {
+            Some(ballot) => quadratic_cost(ballot.weight)?,
+            None if votes == 0 => return Err(VoteError::NotVoted),
+            None => 0,
+        };
+        let spent = self.credits_spent.get(&(round_id, voter)).copied().unwrap_or(0);
+        let spent = spent.saturating_sub(refund);
+        let spent = quadratic_cost(votes)?
+            .checked_add(spent)
+            .filter(|spent| *spent <= credits)
+            .ok_or(VoteError::InsufficientCredits)?;
+
+        // Work out the new tallies before touching any state
+        let mut yes_votes = proposal.yes_votes;
+        let mut no_votes = proposal.no_votes;
+        if let Some(ballot) = previous {
+            match ballot.choice {
+                Choice::Standard(VoteOption::Yes) => yes_votes -= ballot.weight,
+                _ => no_votes -= ballot.weight,
+            }
+        }
+        let option = if support { VoteOption::Yes } else { VoteOption::No };
+        let tally = if support { &mut yes_votes } else { &mut no_votes };
+        *tally = tally.checked_add(votes).ok_or(VoteError::TallyOverflow)?;
+
+        let proposal = self.proposal_mut(proposal_id)?;
+        proposal.yes_votes = yes_votes;
+        proposal.no_votes = no_votes;
+        self.credits_spent.insert((round_id, voter), spent);
+        let proposal_votes = self.votes.entry(proposal_id).or_default();
+        let choice = Choice::Standard(option);
+        let (topic, cast, weight) = match previous {
+            Some(ballot) if votes == 0 => {
+                proposal_votes.remove(&voter);
+                (VoteCast::RETRACTED_TOPIC, None, ballot.weight)
+            }
+            Some(_) => {
+                proposal_votes.insert(voter, Ballot { choice, weight: votes });
+                (VoteCast::CHANGED_TOPIC, Some(choice), votes)
+            }
+            None => {
+                proposal_votes.insert(voter, Ballot { choice, weight: votes });
+                (VoteCast::TOPIC, Some(choice), votes)
+            }
+        };
+        emit(topic, VoteCast { proposal_id, voter, choice: cast, weight });
+        Ok(())
+    }
+
+    /// Query the voice credits an account has in a round
+    fn round_credits(&self, round: &Round, account: &Account) -> u64 {
+        self.voting_power_at(&VotingPowerStrategy::DEFAULT, account, round.snapshot_height)
+    }
+
+    // ==================== Election Functions ====================
+
+    /// Create a ranked-choice election for one or more council seats (Proposer only)
+    /// Voting power is read with the global strategy at the last completed block
+    pub fn create_election(&mut self, params: NewElection) -> Result<u32, VoteError> {
+        let NewElection {
+            description,
+            start_height,
+            end_height,
+            candidates,
+            seats,
+            install_council,
+        } = params;
+        self.ensure_not_paused()?;
+        self.ensure_role(Role::Proposer)?;
+        if description.len() > MAX_PROPOSAL_DESC_LEN {
+            return Err(VoteError::DescriptionTooLong);
+        }
+        if !(2..=MAX_CANDIDATES).contains(&candidates.len()) || !all_distinct(&candidates) {
+            return Err(VoteError::InvalidCandidates);
+        }
+        if seats == 0 || seats as usize >= candidates.len() {
+            return Err(VoteError::InvalidSeats);
+        }
+        if start_height >= end_height {
+            return Err(VoteError::InvalidVotingWindow);
+        }
+        if end_height <= block_height() {
+            return Err(VoteError::VotingWindowInPast);
+        }
+
+        let id = self.next_election_id;
+        self.next_election_id += 1;
+        let snapshot_height = block_height().saturating_sub(1);
+        emit(
+            ElectionCreated::TOPIC,
+            ElectionCreated {
+                election_id: id,
+                seats,
+                candidate_count: candidates.len() as u32,
+                start_height,
+                end_height,
+                snapshot_height,
+            },
+        );
+        self.elections.insert(id, Election {
+            id,
+            description,
+            candidates,
+            seats,
+            start_height,
+            end_height,
+            snapshot_height,
+            strategy: self.voting_power_strategy.clone(),
+            install_council,
+            total_weight: 0,
+            finalized: false,
+            winners: Vec::new(),
+        });
+        self.election_ballots.insert(id, BTreeMap::new());
+        Ok(id)
+    }
+
+    /// Rank candidates of an election, most preferred first
+    /// Candidates left off the ranking get no support from the ballot; delegation does not apply
+    /// Voter is determined from the call stack
+    /// - ranking: indices into the election's `candidates`
+    pub fn vote_ranked(&mut self, election_id: u32, ranking: Vec<u8>) -> Result<(), VoteError> {
+        self.ensure_not_paused()?;
+        let voter = sender_account()?;
+        if let Account::Contract(contract) = voter {
+            if !self.contract_voters.contains(&contract) {
+                return Err(VoteError::ContractsCannotVote);
+            }
+        }
+
+        let election = self.elections.get(&election_id).ok_or(VoteError::ElectionNotFound)?;
+        let height = block_height();
+        if height < election.start_height {
+            return Err(VoteError::VotingNotStarted);
+        }
+        if height >= election.end_height {
+            return Err(VoteError::VotingEnded);
+        }
+        let candidate_count = election.candidates.len();
+        if ranking.is_empty()
+            || !all_distinct(&ranking)
+            || ranking.iter().any(|c| *c as usize >= candidate_count)
+        {
+            return Err(VoteError::InvalidRanking);
+        }
+        if self.election_ballots.get(&election_id).is_some_and(|b| b.contains_key(&voter)) {
+            return Err(VoteError::AlreadyVoted);
+        }
+        let weight = self.voting_power_at(&election.strategy, &voter, election.snapshot_height);
+        if weight == 0 {
+            return Err(VoteError::NoVotingPower);
+        }
+        let total_weight =
+            election.total_weight.checked_add(weight).ok_or(VoteError::TallyOverflow)?;
+
+        if let Some(election) = self.elections.get_mut(&election_id) {
+            election.total_weight = total_weight;
+        }
+        self.election_ballots
+            .entry(election_id)
+            .or_default()
+            .insert(voter, RankedBallot { ranking, weight });
+        emit(RankedBallotCast::TOPIC, RankedBallotCast { election_id, voter, weight });
+        Ok(())
+    }
+
+    /// Count an election once voting has ended and record its winners (Closer only)
+    /// One seat is counted by instant-runoff and several by weighted STV (see `count_stv`);
+    /// an election without ballots elects nobody
+    /// Winners of an election that installs the council replace every Council role holder
+    pub fn finalize_election(&mut self, election_id: u32) -> Result<Vec<Account>, VoteError> {
+        self.ensure_role(Role::Closer)?;
+        let election = self.elections.get(&election_id).ok_or(VoteError::ElectionNotFound)?;
+        if election.finalized {
+            return Err(VoteError::ElectionFinalized);
+        }
+        if block_height() < election.end_height {
+            return Err(VoteError::VotingNotEnded);
+        }
+
+        let ballots: Vec<RankedBallot> = self
+            .election_ballots
+            .get(&election_id)
+            .map(|ballots| ballots.values().cloned().collect())
+            .unwrap_or_default();
+        let winners: Vec<Account> = if ballots.is_empty() {
+            Vec::new()
+        } else {
+            count_stv(election.candidates.len(), &ballots, election.seats as usize)
+                .into_iter()
+                .map(|index| election.candidates[index as usize])
+                .collect()
+        };
+        let install = election.install_council && !winners.is_empty();
+
+        if let Some(election) = self.elections.get_mut(&election_id) {
+            election.finalized = true;
+            election.winners = winners.clone();
+        }
+        if install {
+            self.install_council(&winners);
+        }
+        emit(ElectionFinalized::TOPIC, ElectionFinalized { election_id, winners: winners.clone() });
+        Ok(winners)
+    }
+
+    /// Replace the holders of the Council role with an election's winners
+    fn install_council(&mut self, winners: &[Account]) {
+        let role = Role::Council;
+        let council: BTreeSet<Account> = winners.iter().copied().collect();
+        let previous = self.roles.insert(role, council.clone()).unwrap_or_default();
+        for account in previous.difference(&council) {
+            emit(RoleChanged::REVOKED_TOPIC, RoleChanged { role, account: *account });
+        }
+        for account in council.difference(&previous) {
+            emit(RoleChanged::GRANTED_TOPIC, RoleChanged { role, account: *account });
+        }
+    }
+
+    // ==================== Delegation Functions ====================
+
+    /// Delegate the caller's voting power on every proposal to another account
+    /// Replaces any existing global delegation; chains are followed transitively
+    pub fn delegate(&mut self, to: Account) -> Result<(), VoteError> {
+        self.delegate_scoped(DelegationScope::Global, to)
+    }
+
+    /// Stop delegating the caller's voting power globally
+    pub fn undelegate(&mut self) -> Result<(), VoteError> {
+        self.undelegate_scoped(DelegationScope::Global)
+    }
+
+    /// Delegate the caller's voting power within a scope to another account
+    /// Replaces any existing delegation in the same scope
+    pub fn delegate_scoped(
+        &mut self,
+        scope: DelegationScope,
+        to: Account,
+    ) -> Result<(), VoteError> {
+        self.ensure_not_frozen()?;
+        let delegator = sender_account()?;
+        if to == delegator {
+            return Err(VoteError::CannotDelegateSelf);
+        }
+        match &scope {
+            DelegationScope::Global => {}
+            DelegationScope::Category(category) => {
+                if category.is_empty() {
+                    return Err(VoteError::EmptyCategory);
+                }
+                if category.len() > MAX_CATEGORY_LEN {
+                    return Err(VoteError::CategoryTooLong);
+                }
+            }
+            DelegationScope::Proposal(id) => {
+                self.proposal(*id)?;
+            }
+        }
+
+        // Walk the delegate's chain in every scope chain the new edge takes part
+        // in (a global edge also applies within each category and proposal) to
+        // make sure it never leads back to the caller
+        for scopes in self.chains_through(delegator, &scope) {
+            let mut next = Some(to);
+            // Bounded in case other scopes form a loop
+            for _ in 0..=self.delegates.len() {
+                let Some(account) = next else { break };
+                if account == delegator {
+                    return Err(VoteError::DelegationCycle);
+                }
+                next = self.resolve_delegate(account, &scopes);
+            }
+        }
+
+        if let Some(previous) = self.delegates.insert((delegator, scope.clone()), to) {
+            self.remove_delegator(previous, &scope, delegator);
+        }
+        self.delegators.entry((to, scope.clone())).or_default().insert(delegator);
+        emit(DelegationChanged::TOPIC, DelegationChanged { delegator, scope, delegate: Some(to) });
+        Ok(())
+    }
+
+    /// Stop delegating the caller's voting power within a scope
+    pub fn undelegate_scoped(&mut self, scope: DelegationScope) -> Result<(), VoteError> {
+        self.ensure_not_frozen()?;
+        let delegator = sender_account()?;
+        let previous = self.delegates
+            .remove(&(delegator, scope.clone()))
+            .ok_or(VoteError::NotDelegating)?;
+        self.remove_delegator(previous, &scope, delegator);
+        emit(DelegationChanged::TOPIC, DelegationChanged { delegator, scope, delegate: None });
+        Ok(())
+    }
+
+    /// Remove a delegator from a delegate's reverse index
+    fn remove_delegator(&mut self, delegate: Account, scope: &DelegationScope, delegator: Account) {
+        let key = (delegate, scope.clone());
+        if let Some(delegators) = self.delegators.get_mut(&key) {
+            delegators.remove(&delegator);
+            if delegators.is_empty() {
+                self.delegators.remove(&key);
+            }
+        }
+    }
+
+    // ==================== Archiving Functions ====================
+
+    /// Archive finalized proposals whose voting ended at least `archive_age` blocks ago
+    /// Succeeded proposals with actions wait until those are executed or expire
+    /// Anyone may call this; at most `limit` proposals are archived per call
+    /// Returns the number of proposals archived
+    pub fn archive_proposals(&mut self, limit: u32) -> u32 {
+        if self.successor.is_some() {
+            return 0;
+        }
+        let height = block_height();
+        let ids: Vec<u32> = self
+            .proposals
+            .values()
+            .filter(|p| p.status.is_finalized())
+            .filter(|p| p.status != ProposalStatus::Succeeded || p.actions.is_empty())
+            .filter(|p| self.status_at(p, height) != ProposalStatus::Queued)
+            .filter(|p| p.end_height.saturating_add(self.archive_age) <= height)
+            .map(|p| p.id)
+            .take(limit as usize)
+            .collect();
+        for id in &ids {
+            self.archive_proposal(*id);
+        }
+        ids.len() as u32
+    }
+
+    /// Move a proposal into the archive, freeing its ballots and scoped delegations
+    /// The last proposal of a quadratic round also frees the credits spent in it
+    fn archive_proposal(&mut self, proposal_id: u32) {
+        let Some(mut proposal) = self.proposals.remove(&proposal_id) else {
+            return;
+        };
+        if let Some(round_id) = proposal.round {
+            let pending = self.rounds.get(&round_id).is_some_and(|round| {
+                round.proposal_ids.iter().any(|id| self.proposals.contains_key(id))
+            });
+            if !pending {
+                self.credits_spent.retain(|(round, _), _| *round != round_id);
+            }
+        }
+        proposal.status = self.status_at(&proposal, block_height());
+        let votes = self.votes.remove(&proposal_id).unwrap_or_default();
+        self.delegated_votes.remove(&proposal_id);
+
+        // Delegations scoped to this proposal can never apply again
+        let scope = DelegationScope::Proposal(proposal_id);
+        self.delegates.retain(|(_, s), _| *s != scope);
+        self.delegators.retain(|(_, s), _| *s != scope);
+
+        let record = ProposalRecord {
+            proposal,
+            voter_count: votes.len() as u32,
+            votes_commitment: votes_commitment(&votes),
+        };
+        emit(
+            ProposalArchived::TOPIC,
+            ProposalArchived {
+                proposal_id,
+                voter_count: record.voter_count,
+                votes_commitment: record.votes_commitment,
+            },
+        );
+        self.archived.insert(proposal_id, record);
+    }
+
+    // ==================== Migration Functions ====================
+
+    /// Freeze the contract so its state can be moved to `successor` (admin only)
+    /// Every state-changing call fails afterwards; queries keep working
+    /// Live proposals must not hold deposits, since the DUSK stays in this contract
+    pub fn freeze(&mut self, successor: ContractId) -> Result<(), VoteError> {
+        self.ensure_admin()?;
+        if successor == self_id() {
+            return Err(VoteError::NotSuccessor);
+        }
+        if self.proposals.values().any(|p| !p.status.is_finalized() && p.deposit > 0) {
+            return Err(VoteError::UnsettledDeposits);
+        }
+        self.successor = Some(successor);
+        emit(ContractFrozen::TOPIC, ContractFrozen { successor });
+        Ok(())
+    }
+
+    /// Export the full state to the successor (successor contract only, once frozen)
+    pub fn export_state(&self) -> Result<StateSnapshot, VoteError> {
+        let successor = self.successor.ok_or(VoteError::NotSuccessor)?;
+        if sender_account()? != Account::Contract(successor) {
+            return Err(VoteError::NotSuccessor);
+        }
+        Ok(self.snapshot(successor))
+    }
+
+    /// Capture the full state for a successor
+    fn snapshot(&self, successor: ContractId) -> StateSnapshot {
+        StateSnapshot {
+            version: self.state_version,
+            source: self_id(),
+            successor,
+            admin: self.admin,
+            token_contract: self.token_contract,
+            voting_rules: self.voting_rules,
+            voting_power_strategy: self.voting_power_strategy.clone(),
+            proposals: self.proposals.values().cloned().collect(),
+            votes: flatten_by_proposal(&self.votes),
+            delegates: self.delegates.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+            delegated_votes: flatten_by_proposal(&self.delegated_votes),
+            next_proposal_id: self.next_proposal_id,
+            archived: self.archived.values().cloned().collect(),
+            archive_age: self.archive_age,
+            execution_delay: self.execution_delay,
+            grace_period: self.grace_period,
+            roles: self
+                .roles
+                .iter()
+                .map(|(role, members)| (*role, members.iter().copied().collect()))
+                .collect(),
+            submission_policy: self.submission_policy,
+            contract_voters: self.contract_voters.iter().copied().collect(),
+            committee: self.committee.clone(),
+            paused: self.paused,
+            rounds: self.rounds.values().cloned().collect(),
+            next_round_id: self.next_round_id,
+            credits_spent: self.credits_spent.iter().map(|(k, v)| (*k, *v)).collect(),
+            elections: self.elections.values().cloned().collect(),
+            next_election_id: self.next_election_id,
+            election_ballots: flatten_by_proposal(&self.election_ballots),
+        }
+    }
+
+    /// Import the state of a frozen contract that named this one as its successor (admin only)
+    /// Only a contract without proposals can import
+    pub fn migrate_from(&mut self, source: ContractId) -> Result<(), VoteError> {
+        self.ensure_admin()?;
+        if !self.proposals.is_empty() || !self.archived.is_empty() || self.next_proposal_id > 0 {
+            return Err(VoteError::StateNotEmpty);
+        }
+        let snapshot = fetch_snapshot(source)?;
+        if snapshot.source != source || snapshot.successor != self_id() {
+            return Err(VoteError::NotSuccessor);
+        }
+        self.import_snapshot(snapshot)
+    }
+
+    /// Replace the state with a verified snapshot
+    /// Accounts referring to the source contract (its committee) now refer to this one
+    /// The source stays paused here if it was; its pending committee operations are dropped
+    fn import_snapshot(&mut self, snapshot: StateSnapshot) -> Result<(), VoteError> {
+        check_snapshot_version(snapshot.version)?;
+        let source = snapshot.source;
+        let rehome = |account: Account| match account {
+            Account::Contract(id) if id == source => committee_account(),
+            account => account,
+        };
+
+        self.admin = rehome(snapshot.admin);
+        self.pending_admin = None;
+        self.token_contract = snapshot.token_contract;
+        self.voting_rules = snapshot.voting_rules;
+        self.voting_power_strategy = snapshot.voting_power_strategy;
+        self.proposals = snapshot
+            .proposals
+            .into_iter()
+            .map(|mut proposal| {
+                proposal.creator = rehome(proposal.creator);
+                (proposal.id, proposal)
+            })
+            .collect();
+        self.votes = nest_by_proposal(snapshot.votes);
+        self.delegated_votes = nest_by_proposal(snapshot.delegated_votes);
+        self.delegates = BTreeMap::new();
+        self.delegators = BTreeMap::new();
+        for ((delegator, scope), delegate) in snapshot.delegates {
+            self.delegators.entry((delegate, scope.clone())).or_default().insert(delegator);
+            self.delegates.insert((delegator, scope), delegate);
+        }
+        self.next_proposal_id = snapshot.next_proposal_id;
+        self.live_proposals =
+            self.proposals.values().filter(|p| !p.status.is_finalized()).count() as u32;
+        self.archived = snapshot.archived.into_iter().map(|r| (r.proposal.id, r)).collect();
+        self.archive_age = snapshot.archive_age;
+        self.execution_delay = snapshot.execution_delay;
+        self.grace_period = snapshot.grace_period;
+        self.roles = snapshot
+            .roles
+            .into_iter()
+            .map(|(role, members)| (role, members.into_iter().map(rehome).collect()))
+            .collect();
+        self.submission_policy = snapshot.submission_policy;
+        self.contract_voters = snapshot.contract_voters.into_iter().collect();
+        self.committee = snapshot.committee;
+        self.rounds = snapshot.rounds.into_iter().map(|round| (round.id, round)).collect();
+        self.next_round_id = snapshot.next_round_id;
+        self.credits_spent = snapshot.credits_spent.into_iter().collect();
+        self.elections = snapshot.elections.into_iter().map(|e| (e.id, e)).collect();
+        self.next_election_id = snapshot.next_election_id;
+        self.election_ballots = nest_by_proposal(snapshot.election_ballots);
+        self.operations = BTreeMap::new();
+        self.paused = snapshot.paused;
+        self.predecessor = Some(source);
+
+        emit(
+            StateMigrated::TOPIC,
+            StateMigrated {
+                source,
+                version: snapshot.version,
+                proposal_count: self.proposals.len() as u32,
+                archived_count: self.archived.len() as u32,
+            },
+        );
+        Ok(())
+    }
+
+    // ==================== Query Functions ====================
+
+    /// Derive the lifecycle status of a proposal at the given block height
+    fn status_at(&self, proposal: &Proposal, height: u64) -> ProposalStatus {
+        if proposal.status == ProposalStatus::Queued && height >= proposal.expires_at {
+            ProposalStatus::Expired
+        } else if proposal.status.is_finalized() {
+            proposal.status
+        } else if height < proposal.start_height {
+            ProposalStatus::Pending
+        } else if height < proposal.end_height {
+            ProposalStatus::Active
+        } else {
+            match evaluate_proposal(self.token_contract, proposal) {
+                ProposalOutcome::Passed => ProposalStatus::Succeeded,
+                _ => ProposalStatus::Defeated,
+            }
+        }
+    }
+
+    /// Clone a proposal with its status derived at the given block height
+    fn with_status_at(&self, proposal: &Proposal, height: u64) -> Proposal {
+        let mut proposal = proposal.clone();
+        proposal.status = self.status_at(&proposal, height);
+        proposal
+    }
+
+    /// Get proposal details (status derived at the current block height)
+    /// Archived proposals are returned with their final status and tallies
+    pub fn get_proposal(&self, proposal_id: u32) -> Option<Proposal> {
+        let height = block_height();
+        self.proposals
+            .get(&proposal_id)
+            .map(|p| self.with_status_at(p, height))
+            .or_else(|| self.archived.get(&proposal_id).map(|r| r.proposal.clone()))
+    }
+
+    /// Get proposal details in the original layout (abstentions are left out)
+    pub fn get_proposal_v1(&self, proposal_id: u32) -> Option<ProposalV1> {
+        self.get_proposal(proposal_id).map(|p| ProposalV1::from(&p))
+    }
+
+    /// Get the archived record of a proposal (None while it is still live)
+    pub fn get_archived_proposal(&self, proposal_id: u32) -> Option<ProposalRecord> {
+        self.archived.get(&proposal_id).cloned()
+    }
+
+    /// Get the number of archived proposals
+    pub fn archived_count(&self) -> u32 {
+        self.archived.len() as u32
+    }
+
+    /// Get the number of blocks after voting ends before a proposal can be archived
+    pub fn archive_age(&self) -> u64 {
+        self.archive_age
+    }
+
+    /// Get the timelock delay and grace period in blocks
+    pub fn timelock(&self) -> (u64, u64) {
+        (self.execution_delay, self.grace_period)
+    }
+
+    /// Check whether an account holds a role
+    pub fn has_role(&self, role: Role, account: Account) -> bool {
+        self.roles.get(&role).is_some_and(|members| members.contains(&account))
+    }
+
+    /// Check whether the contract is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Get the layout version of the contract state
+    pub fn state_version(&self) -> u32 {
+        self.state_version
+    }
+
+    /// Get the contract this state was moved to (the contract is frozen if set)
+    pub fn successor(&self) -> Option<ContractId> {
+        self.successor
+    }
+
+    /// Get the contract this state was imported from
+    pub fn predecessor(&self) -> Option<ContractId> {
+        self.predecessor
+    }
+
+    /// Get the admin committee (if the admin is a committee)
+    pub fn committee(&self) -> Option<Committee> {
+        self.committee.clone()
+    }
+
+    /// Get a pending committee operation
+    pub fn get_operation(&self, operation_id: u32) -> Option<PendingOperation> {
+        self.operations.get(&operation_id).cloned()
+    }
+
+    /// Get all pending committee operations, oldest first
+    pub fn pending_operations(&self) -> Vec<PendingOperation> {
+        self.operations.values().cloned().collect()
+    }
+
+    /// Get the accounts holding a role, in account order
+    pub fn role_members(&self, role: Role) -> Vec<Account> {
+        self.roles.get(&role).map(|members| members.iter().copied().collect()).unwrap_or_default()
+    }
+
+    /// Get all proposals that have not been archived (status derived at the current block height)
+    pub fn get_all_proposals(&self) -> Vec<Proposal> {
+        let height = block_height();
+        self.proposals.values().map(|p| self.with_status_at(p, height)).collect()
+    }
+
+    /// Get a page of proposals matching a filter (status derived at the current block height)
+    /// Proposals are in creation order, or newest first; at most 50 are returned
+    /// Archived proposals are not included
+    pub fn get_proposals(
+        &self,
+        offset: u32,
+        limit: u32,
+        filter: ProposalFilter,
+        newest_first: bool,
+    ) -> Vec<Proposal> {
+        let height = block_height();
+        let ordered: Vec<&Proposal> = if newest_first {
+            self.proposals.values().rev().collect()
+        } else {
+            self.proposals.values().collect()
+        };
+        ordered
+            .into_iter()
+            .filter(|p| filter.creator.iter().all(|creator| p.creator == *creator))
+            .filter(|p| filter.category.iter().all(|category| p.category == *category))
+            .filter(|p| filter.status.iter().all(|status| self.status_at(p, height) == *status))
+            .skip(offset as usize)
+            .take((limit as usize).min(MAX_PAGE_SIZE))
+            .map(|p| self.with_status_at(p, height))
+            .collect()
+    }
+
+    /// Get the lifecycle status of a proposal at the current block height
+    pub fn proposal_status(&self, proposal_id: u32) -> Option<ProposalStatus> {
+        let height = block_height();
+        self.proposals
+            .get(&proposal_id)
+            .map(|p| self.status_at(p, height))
+            .or_else(|| self.archived.get(&proposal_id).map(|r| r.proposal.status))
+    }
+
+    /// Get the number of proposals that have not been archived
+    pub fn proposal_count(&self) -> u32 {
+        self.proposals.len() as u32
+    }
+
+    /// Check if caller has voted on a proposal
+    pub fn has_voted(&self, proposal_id: u32) -> bool {
+        let Ok(voter) = sender_account() else { return false };
+        self.votes
+            .get(&proposal_id)
+            .map(|v| v.contains_key(&voter))
+            .unwrap_or(false)
+    }
+
+    /// Check if a specific account has voted on a proposal
+    pub fn has_account_voted(&self, public_key: PublicKey, proposal_id: u32) -> bool {
+        let account = Account::External(public_key);
+        self.votes
+            .get(&proposal_id)
+            .map(|v| v.contains_key(&account))
+            .unwrap_or(false)
+    }
+
+    /// Get vote weight for caller on a proposal (0 if not voted)
+    pub fn get_vote_weight(&self, proposal_id: u32) -> u64 {
+        let Ok(voter) = sender_account() else { return 0 };
+        self.votes
+            .get(&proposal_id)
+            .and_then(|v| v.get(&voter))
+            .map(|ballot| ballot.weight)
+            .unwrap_or(0)
+    }
+
+    /// Get vote weight for a specific account on a proposal (0 if not voted)
+    pub fn get_account_vote_weight(&self, public_key: PublicKey, proposal_id: u32) -> u64 {
+        let account = Account::External(public_key);
+        self.votes
+            .get(&proposal_id)
+            .and_then(|v| v.get(&account))
+            .map(|ballot| ballot.weight)
+            .unwrap_or(0)
+    }
+
+    /// Get the ballot an account cast on a proposal (None if not voted)
+    pub fn get_ballot(&self, account: Account, proposal_id: u32) -> Option<Ballot> {
+        self.votes
+            .get(&proposal_id)
+            .and_then(|v| v.get(&account))
+            .copied()
+    }
+
+    /// Get a quadratic voting round by ID
+    pub fn get_round(&self, round_id: u32) -> Option<Round> {
+        self.rounds.get(&round_id).cloned()
+    }
+
+    /// Get an election by ID
+    pub fn get_election(&self, election_id: u32) -> Option<Election> {
+        self.elections.get(&election_id).cloned()
+    }
+
+    /// Get an account's ranked ballot in an election
+    pub fn get_ranked_ballot(&self, account: Account, election_id: u32) -> Option<RankedBallot> {
+        self.election_ballots
+            .get(&election_id)
+            .and_then(|ballots| ballots.get(&account))
+            .cloned()
+    }
+
+    /// Get the voice credits an account has left to spend in a round
+    /// Spending is forgotten once every proposal in the round has been archived
+    pub fn remaining_credits(&self, account: Account, round_id: u32) -> u64 {
+        let Some(round) = self.rounds.get(&round_id) else {
+            return 0;
+        };
+        let spent = self.credits_spent.get(&(round_id, account)).copied().unwrap_or(0);
+        self.round_credits(round, &account).saturating_sub(spent)
+    }
+
+    /// Get the rules applied to proposals created without their own
+    pub fn voting_rules(&self) -> VotingRules {
+        self.voting_rules
+    }
+
+    /// Get the voting power strategy applied to proposals created without their own
+    pub fn voting_power_strategy(&self) -> VotingPowerStrategy {
+        self.voting_power_strategy.clone()
+    }
+
+    /// Get who may create proposals
+    pub fn submission_policy(&self) -> SubmissionPolicy {
+        self.submission_policy
+    }
+
+    /// Get the contracts allowed to vote
+    pub fn contract_voters(&self) -> Vec<ContractId> {
+        self.contract_voters.iter().copied().collect()
+    }
+
+    /// Check if a contract is allowed to vote
+    pub fn is_contract_voter(&self, contract: ContractId) -> bool {
+        self.contract_voters.contains(&contract)
+    }
+
+    /// Get admin account
+    pub fn admin(&self) -> Account {
+        self.admin
+    }
+
+    /// Check if caller is admin
+    pub fn is_admin(&self) -> bool {
+        sender_account() == Ok(self.admin)
+    }
+
+    /// Get token balance for a public key (queries token contract)
+    pub fn get_balance(&self, public_key: PublicKey) -> u64 {
+        get_token_balance(self.token_contract, &Account::External(public_key))
+    }
+
+    /// Get voting power for an account on a proposal
+    /// (its balance at the snapshot height plus weight delegated to it by non-voters)
+    pub fn get_voting_power(&self, account: Account, proposal_id: u32) -> u64 {
+        self.proposals
+            .get(&proposal_id)
+            .map(|p| {
+                self.delegated_accounts(proposal_id, account)
+                    .iter()
+                    .chain([&account])
+                    .fold(0u64, |sum, a| {
+                        sum.saturating_add(self.voting_power_at(&p.strategy, a, p.snapshot_height))
+                    })
+            })
+            .unwrap_or(0)
+    }
+
+    /// Get the account an account delegates to within a scope (None if not delegating)
+    pub fn delegate_of(&self, account: Account, scope: DelegationScope) -> Option<Account> {
+        self.delegates.get(&(account, scope)).copied()
+    }
+
+    /// Get the accounts delegating directly to an account within a scope
+    pub fn delegators_of(&self, account: Account, scope: DelegationScope) -> Vec<Account> {
+        self.delegators
+            .get(&(account, scope))
+            .map(|delegators| delegators.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Get the account that an account's weight goes to on a proposal,
+    /// after proposal, category and global delegations are considered
+    pub fn effective_delegate(&self, account: Account, proposal_id: u32) -> Option<Account> {
+        let scopes = self.scope_chain(&DelegationScope::Proposal(proposal_id));
+        self.resolve_delegate(account, &scopes)
+    }
+
+    /// Get the token contract ID used for voting weight
+    pub fn token_contract(&self) -> ContractId {
+        self.token_contract
+    }
+}
+
+// ==================== Contract Entry Points ====================
+// These are only compiled for WASM targets
+
+#[cfg(target_family = "wasm")]
+mod entry_points {
+    use super::*;
+
+/// Initialize contract with specified admin and token contract
+#[no_mangle]
+pub unsafe fn init(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(admin, token_contract): (Account, ContractId)| {
+        STATE.init(admin, token_contract);
+    })
+}
+
+/// Add proposal (admin only unless permissionless) - caller determined from call stack
+#[no_mangle]
+pub unsafe fn add_proposal(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |params: NewProposal| {
+        STATE.add_proposal(params)
+    })
+}
+
+/// Close proposal (Closer only) - caller determined from call stack
+#[no_mangle]
+pub unsafe fn close_proposal(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |proposal_id: u32| {
+        STATE.close_proposal(proposal_id)
+    })
+}
+
+/// Cancel proposal (Canceller only) - caller determined from call stack
+#[no_mangle]
+pub unsafe fn cancel_proposal(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |proposal_id: u32| {
+        STATE.cancel_proposal(proposal_id)
+    })
+}
+
+/// Vote on proposal - voter determined from call stack
+#[no_mangle]
+pub unsafe fn vote(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(proposal_id, vote_yes): (u32, bool)| {
+        STATE.vote(proposal_id, vote_yes)
+    })
+}
+
+/// Vote on proposal with any ballot option - voter determined from call stack
+#[no_mangle]
+pub unsafe fn cast_vote(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(proposal_id, option): (u32, VoteOption)| {
+        STATE.cast_vote(proposal_id, option)
+    })
+}
+
+/// Vote for an option of a multi-choice proposal - voter determined from call stack
+#[no_mangle]
+pub unsafe fn vote_choice(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(proposal_id, option_index): (u32, u32)| {
+        STATE.vote_choice(proposal_id, option_index)
+    })
+}
+
+/// Change the caller's vote on an open proposal - voter determined from call stack
+#[no_mangle]
+pub unsafe fn change_vote(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(proposal_id, choice): (u32, Choice)| {
+        STATE.change_vote(proposal_id, choice)
+    })
+}
+
+/// Retract the caller's vote on an open proposal - voter determined from call stack
+#[no_mangle]
+pub unsafe fn retract_vote(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |proposal_id: u32| STATE.retract_vote(proposal_id))
+}
+
+/// Get proposal by ID
+#[no_mangle]
+pub unsafe fn get_proposal(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |proposal_id: u32| STATE.get_proposal(proposal_id))
+}
+
+/// Get proposal by ID in the original layout
+#[no_mangle]
+pub unsafe fn get_proposal_v1(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |proposal_id: u32| STATE.get_proposal_v1(proposal_id))
+}
+
+/// Get all proposals
+#[no_mangle]
+pub unsafe fn get_all_proposals(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.get_all_proposals())
+}
+
+/// Get a filtered page of proposals, optionally newest first
+#[no_mangle]
+pub unsafe fn get_proposals(arg_len: u32) -> u32 {
+    type Args = (u32, u32, ProposalFilter, bool);
+    abi::wrap_call(arg_len, |(offset, limit, filter, newest_first): Args| {
+        STATE.get_proposals(offset, limit, filter, newest_first)
+    })
+}
+
+/// Get proposal voting status at the current block height
+#[no_mangle]
+pub unsafe fn proposal_status(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |proposal_id: u32| STATE.proposal_status(proposal_id))
+}
+
+/// Get proposal count
+#[no_mangle]
+pub unsafe fn proposal_count(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.proposal_count())
+}
+
+/// Check if caller has voted on proposal
+#[no_mangle]
+pub unsafe fn has_voted(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |proposal_id: u32| {
+        STATE.has_voted(proposal_id)
+    })
+}
+
+/// Check if specific account has voted on proposal
+#[no_mangle]
+pub unsafe fn has_account_voted(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(public_key, proposal_id): (PublicKey, u32)| {
+        STATE.has_account_voted(public_key, proposal_id)
+    })
+}
+
+/// Get the ballot a specific account cast on proposal
+#[no_mangle]
+pub unsafe fn get_ballot(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(account, proposal_id): (Account, u32)| {
+        STATE.get_ballot(account, proposal_id)
+    })
+}
+
+/// Get vote weight for caller on proposal
+#[no_mangle]
+pub unsafe fn get_vote_weight(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |proposal_id: u32| {
+        STATE.get_vote_weight(proposal_id)
+    })
+}
+
+/// Get vote weight for specific account on proposal
+#[no_mangle]
+pub unsafe fn get_account_vote_weight(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(public_key, proposal_id): (PublicKey, u32)| {
+        STATE.get_account_vote_weight(public_key, proposal_id)
+    })
+}
+
+/// Get token balance for a public key
+#[no_mangle]
+pub unsafe fn get_balance(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |public_key: PublicKey| STATE.get_balance(public_key))
+}
+
+/// Get voting power for an account at a proposal's snapshot height
+#[no_mangle]
+pub unsafe fn get_voting_power(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(account, proposal_id): (Account, u32)| {
+        STATE.get_voting_power(account, proposal_id)
+    })
+}
+
+/// Get the token contract ID used for voting weight
+#[no_mangle]
+pub unsafe fn token_contract(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.token_contract())
+}
+
+/// Get admin account
+#[no_mangle]
+pub unsafe fn admin(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.admin())
+}
+
+/// Check if caller is admin
+#[no_mangle]
+pub unsafe fn is_admin(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.is_admin())
+}
+
+/// Get the global voting rules
+#[no_mangle]
+pub unsafe fn voting_rules(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.voting_rules())
+}
+
+/// Set the global voting rules (ConfigManager only)
+#[no_mangle]
+pub unsafe fn set_voting_rules(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |rules: VotingRules| STATE.set_voting_rules(rules))
+}
+
+/// Get the global voting power strategy
+#[no_mangle]
+pub unsafe fn voting_power_strategy(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.voting_power_strategy())
+}
+
+/// Set the global voting power strategy (ConfigManager only)
+#[no_mangle]
+pub unsafe fn set_voting_power_strategy(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |strategy: VotingPowerStrategy| {
+        STATE.set_voting_power_strategy(strategy)
+    })
+}
+
+/// Get who may create proposals
+#[no_mangle]
+pub unsafe fn submission_policy(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.submission_policy())
+}
+
+/// Set who may create proposals (ConfigManager only)
+#[no_mangle]
+pub unsafe fn set_submission_policy(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |policy: SubmissionPolicy| STATE.set_submission_policy(policy))
+}
+
+/// Let a contract vote with its own balance (ConfigManager only)
+#[no_mangle]
+pub unsafe fn allow_contract_voter(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |contract: ContractId| STATE.allow_contract_voter(contract))
+}
+
+/// Take a contract off the voter allowlist (ConfigManager only)
+#[no_mangle]
+pub unsafe fn remove_contract_voter(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |contract: ContractId| STATE.remove_contract_voter(contract))
+}
+
+/// Get the contracts allowed to vote
+#[no_mangle]
+pub unsafe fn contract_voters(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.contract_voters())
+}
+
+/// Check if a contract is allowed to vote
+#[no_mangle]
+pub unsafe fn is_contract_voter(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |contract: ContractId| STATE.is_contract_voter(contract))
+}
+
+// ==================== Round Entry Points ====================
+
+/// Create a quadratic voting round (Proposer only)
+#[no_mangle]
+pub unsafe fn create_round(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |params: NewRound| STATE.create_round(params))
+}
+
+/// Cast quadratic votes on a round proposal - voter determined from call stack
+#[no_mangle]
+pub unsafe fn vote_quadratic(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(proposal_id, support, votes): (u32, bool, u64)| {
+        STATE.vote_quadratic(proposal_id, support, votes)
+    })
+}
+
+/// Get a quadratic voting round by ID
+#[no_mangle]
+pub unsafe fn get_round(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |round_id: u32| STATE.get_round(round_id))
+}
+
+/// Get the voice credits an account has left in a round
+#[no_mangle]
+pub unsafe fn remaining_credits(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(account, round_id): (Account, u32)| {
+        STATE.remaining_credits(account, round_id)
+    })
+}
+
+// ==================== Election Entry Points ====================
+
+/// Create a council election (Proposer only)
+#[no_mangle]
+pub unsafe fn create_election(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |params: NewElection| STATE.create_election(params))
+}
+
+/// Cast a ranked ballot in an election - voter determined from call stack
+#[no_mangle]
+pub unsafe fn vote_ranked(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(election_id, ranking): (u32, Vec<u8>)| {
+        STATE.vote_ranked(election_id, ranking)
+    })
+}
+
+/// Count an election and record its winners (Closer only)
+#[no_mangle]
+pub unsafe fn finalize_election(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |election_id: u32| STATE.finalize_election(election_id))
+}
+
+/// Get an election by ID
+#[no_mangle]
+pub unsafe fn get_election(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |election_id: u32| STATE.get_election(election_id))
+}
+
+/// Get an account's ranked ballot in an election
+#[no_mangle]
+pub unsafe fn get_ranked_ballot(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(account, election_id): (Account, u32)| {
+        STATE.get_ranked_ballot(account, election_id)
+    })
+}
+
+// ==================== Archiving Entry Points ====================
+
+/// Archive up to `limit` finalized proposals past the archive age (anyone may call)
+#[no_mangle]
+pub unsafe fn archive_proposals(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |limit: u32| STATE.archive_proposals(limit))
+}
+
+/// Get the archived record of a proposal
+#[no_mangle]
+pub unsafe fn get_archived_proposal(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |proposal_id: u32| STATE.get_archived_proposal(proposal_id))
+}
+
+/// Get the number of archived proposals
+#[no_mangle]
+pub unsafe fn archived_count(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.archived_count())
+}
+
+/// Get the archive age in blocks
+#[no_mangle]
+pub unsafe fn archive_age(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.archive_age())
+}
+
+/// Set the archive age in blocks (ConfigManager only)
+#[no_mangle]
+pub unsafe fn set_archive_age(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |archive_age: u64| STATE.set_archive_age(archive_age))
+}
+
+// ==================== Timelock Entry Points ====================
+
+/// Queue a succeeded proposal, starting its timelock (anyone may call)
+#[no_mangle]
+pub unsafe fn queue(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |proposal_id: u32| STATE.queue(proposal_id))
+}
+
+/// Cancel a queued proposal during its timelock (Canceller only)
+#[no_mangle]
+pub unsafe fn cancel_queued(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |proposal_id: u32| STATE.cancel_queued(proposal_id))
+}
+
+/// Perform a queued proposal's actions once its timelock has passed (anyone may call)
+#[no_mangle]
+pub unsafe fn execute(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |proposal_id: u32| STATE.execute(proposal_id))
+}
+
+/// Get the timelock delay and grace period in blocks
+#[no_mangle]
+pub unsafe fn timelock(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.timelock())
+}
+
+/// Set the timelock delay and grace period in blocks (ConfigManager only)
+#[no_mangle]
+pub unsafe fn set_timelock(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(execution_delay, grace_period): (u64, u64)| {
+        STATE.set_timelock(execution_delay, grace_period)
+    })
+}
+
+// ==================== Delegation Entry Points ====================
+
+/// Delegate voting power to another account - delegator determined from call stack
+#[no_mangle]
+pub unsafe fn delegate(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |to: Account| STATE.delegate(to))
+}
+
+/// Stop delegating voting power - delegator determined from call stack
+#[no_mangle]
+pub unsafe fn undelegate(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.undelegate())
+}
+
+/// Delegate voting power within a scope - delegator determined from call stack
+#[no_mangle]
+pub unsafe fn delegate_scoped(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(scope, to): (DelegationScope, Account)| {
+        STATE.delegate_scoped(scope, to)
+    })
+}
+
+/// Stop delegating voting power within a scope - delegator determined from call stack
+#[no_mangle]
+pub unsafe fn undelegate_scoped(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |scope: DelegationScope| STATE.undelegate_scoped(scope))
+}
+
+/// Get the account an account delegates to within a scope
+#[no_mangle]
+pub unsafe fn delegate_of(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(account, scope): (Account, DelegationScope)| {
+        STATE.delegate_of(account, scope)
+    })
+}
+
+/// Get the accounts delegating directly to an account within a scope
+#[no_mangle]
+pub unsafe fn delegators_of(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(account, scope): (Account, DelegationScope)| {
+        STATE.delegators_of(account, scope)
+    })
+}
+
+/// Get the account an account's weight goes to on a proposal
+#[no_mangle]
+pub unsafe fn effective_delegate(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(account, proposal_id): (Account, u32)| {
+        STATE.effective_delegate(account, proposal_id)
+    })
+}
+
+// ==================== Committee Entry Points ====================
+
+/// Make a committee the admin, or replace it (admin only)
+#[no_mangle]
+pub unsafe fn set_committee(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |committee: Committee| STATE.set_committee(committee))
+}
+
+/// Submit an admin operation for approval (committee members only)
+#[no_mangle]
+pub unsafe fn submit_operation(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |operation: AdminOperation| STATE.submit_operation(operation))
+}
+
+/// Approve a pending operation (committee members only)
+#[no_mangle]
+pub unsafe fn approve_operation(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |operation_id: u32| STATE.approve_operation(operation_id))
+}
+
+/// Withdraw a pending operation (its submitter only)
+#[no_mangle]
+pub unsafe fn cancel_operation(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |operation_id: u32| STATE.cancel_operation(operation_id))
+}
+
+/// Get the admin committee (if any)
+#[no_mangle]
+pub unsafe fn committee(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.committee())
+}
+
+/// Get a pending committee operation
+#[no_mangle]
+pub unsafe fn get_operation(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |operation_id: u32| STATE.get_operation(operation_id))
+}
+
+/// Get all pending committee operations
+#[no_mangle]
+pub unsafe fn pending_operations(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.pending_operations())
+}
+
+// ==================== Pause Entry Points ====================
+
+/// Halt voting, proposal creation and execution (admin or Pauser only)
+#[no_mangle]
+pub unsafe fn pause(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |reason: String| STATE.pause(reason))
+}
+
+/// Resume normal operation (admin or Pauser only)
+#[no_mangle]
+pub unsafe fn unpause(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |reason: String| STATE.unpause(reason))
+}
+
+/// Check whether the contract is paused
+#[no_mangle]
+pub unsafe fn is_paused(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.is_paused())
+}
+
+// ==================== Migration Entry Points ====================
+
+/// Get the layout version of the contract state
+#[no_mangle]
+pub unsafe fn state_version(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.state_version())
+}
+
+/// Freeze the contract for migration to a successor (admin only)
+#[no_mangle]
+pub unsafe fn freeze(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |successor: ContractId| STATE.freeze(successor))
+}
+
+/// Export the state to the successor (successor contract only)
+#[no_mangle]
+pub unsafe fn export_state(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.export_state())
+}
+
+/// Import the state of a frozen contract (admin only)
+#[no_mangle]
+pub unsafe fn migrate_from(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |source: ContractId| STATE.migrate_from(source))
+}
+
+/// Get the contract the state was moved to (if frozen)
+#[no_mangle]
+pub unsafe fn successor(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.successor())
+}
+
+/// Get the contract the state was imported from
+#[no_mangle]
+pub unsafe fn predecessor(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.predecessor())
+}
+
+// ==================== Role Entry Points ====================
+
+/// Grant a role to an account (admin only)
+#[no_mangle]
+pub unsafe fn grant_role(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(role, account): (Role, Account)| STATE.grant_role(role, account))
+}
+
+/// Revoke a role from an account (admin only)
+#[no_mangle]
+pub unsafe fn revoke_role(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(role, account): (Role, Account)| STATE.revoke_role(role, account))
+}
+
+/// Check whether an account holds a role
+#[no_mangle]
+pub unsafe fn has_role(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(role, account): (Role, Account)| STATE.has_role(role, account))
+}
+
+/// Get the accounts holding a role
+#[no_mangle]
+pub unsafe fn role_members(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |role: Role| STATE.role_members(role))
+}
+
+// ==================== Admin Transfer Entry Points ====================
+
+/// Propose a new admin (admin only) - two-step transfer process
+#[no_mangle]
+pub unsafe fn propose_admin(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |new_admin: Account| {
+        STATE.propose_admin(new_admin)
+    })
+}
+
+/// Accept admin role (pending admin only) - completes the transfer
+#[no_mangle]
+pub unsafe fn accept_admin(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.accept_admin())
+}
+
+/// Cancel pending admin transfer (admin only)
+#[no_mangle]
+pub unsafe fn cancel_admin_proposal(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.cancel_admin_proposal())
+}
+
+/// Get pending admin (if any)
+#[no_mangle]
+pub unsafe fn pending_admin(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |_: ()| STATE.pending_admin())
+}
+
+} // end of entry_points module
+
+// ==================== Unit Tests ====================
+// These tests verify the contract logic without requiring the VM
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    fn create_test_contract(admin_pk: PublicKey, token_contract: ContractId) -> VoteContract {
+        let admin = Account::External(admin_pk);
+        VoteContract {
+            state_version: STATE_VERSION,
+            admin,
+            pending_admin: None,
+            token_contract,
+            voting_rules: VotingRules::DEFAULT,
+            voting_power_strategy: VotingPowerStrategy::DEFAULT,
+            proposals: BTreeMap::new(),
+            votes: BTreeMap::new(),
+            delegates: BTreeMap::new(),
+            delegators: BTreeMap::new(),
+            delegated_votes: BTreeMap::new(),
+            next_proposal_id: 0,
+            live_proposals: 0,
+            archived: BTreeMap::new(),
+            archive_age: DEFAULT_ARCHIVE_AGE,
+            execution_delay: DEFAULT_EXECUTION_DELAY,
+            grace_period: DEFAULT_GRACE_PERIOD,
+            roles: Role::ALL.iter().map(|role| (*role, BTreeSet::from([admin]))).collect(),
+            submission_policy: SubmissionPolicy::ADMIN_ONLY,
+            contract_voters: BTreeSet::new(),
+            committee: None,
+            rounds: BTreeMap::new(),
+            next_round_id: 0,
+            credits_spent: BTreeMap::new(),
+            elections: BTreeMap::new(),
+            next_election_id: 0,
+            election_ballots: BTreeMap::new(),
+            operations: BTreeMap::new(),
+            next_operation_id: 0,
+            committee_executing: false,
+            paused: false,
+            successor: None,
+            predecessor: None,
+        }
+    }
+
+    fn new_proposal(start_height: u64, end_height: u64, rules: Option<VotingRules>) -> NewProposal {
+        NewProposal {
+            description: "Test".to_string(),
+            start_height,
+            end_height,
+            rules,
+            strategy: None,
+            options: Vec::new(),
+            category: String::new(),
+            actions: Vec::new(),
+            deposit: 0,
+            round: None,
+        }
+    }
+
+    /// Move a proposal's voting window into the past
+    /// (block height is fixed at 0 outside the VM)
+    fn end_voting(contract: &mut VoteContract, proposal_id: u32) {
+        let proposal = contract.proposals.get_mut(&proposal_id).unwrap();
+        proposal.start_height = 0;
+        proposal.end_height = 0;
+    }
+
+    fn mock_public_key(seed: u8) -> PublicKey {
+        // Create a deterministic but valid-looking public key for tests
+        let mut bytes = [0u8; 96];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seed.wrapping_add(i as u8);
+        }
+        // Note: This won't create a cryptographically valid key,
+        // but it's sufficient for testing data structures
+        PublicKey::default()
+    }
+
+    // ==================== Proposal Tests ====================
+
+    #[test]
+    fn test_proposal_structure() {
+        let proposal = Proposal {
+            id: 0,
+            description: "Test proposal".to_string(),
+            yes_votes: 100,
+            no_votes: 50,
+            start_height: 10,
+            end_height: 20,
+            snapshot_height: 5,
+            rules: VotingRules::DEFAULT,
+            outcome: None,
+            status: ProposalStatus::Pending,
+            abstain_votes: 25,
+            no_with_veto_votes: 5,
+            options: Vec::new(),
+            option_votes: Vec::new(),
+            winning_option: None,
+            category: String::new(),
+            creator: Account::default(),
+            actions: Vec::new(),
+            execute_after: 0,
+            action_results: Vec::new(),
+            expires_at: 0,
+            deposit: 0,
+            strategy: VotingPowerStrategy::DEFAULT,
+            round: None,
+        };
+
+        assert_eq!(proposal.id, 0);
+        assert_eq!(proposal.description, "Test proposal");
+        assert_eq!(proposal.yes_votes, 100);
+        assert_eq!(proposal.no_votes, 50);
+        assert_eq!(proposal.abstain_votes, 25);
+        assert_eq!(proposal.no_with_veto_votes, 5);
+        assert_eq!(proposal.status, ProposalStatus::Pending);
+    }
+
+    #[test]
+    fn test_proposal_default() {
+        let proposal = Proposal::default();
+
+        assert_eq!(proposal.id, 0);
+        assert_eq!(proposal.description, "");
+        assert_eq!(proposal.yes_votes, 0);
+        assert_eq!(proposal.no_votes, 0);
+        assert_eq!(proposal.start_height, 0);
+        assert_eq!(proposal.end_height, 0);
+        assert_eq!(proposal.snapshot_height, 0);
+        assert_eq!(proposal.rules, VotingRules::DEFAULT);
+        assert!(proposal.outcome.is_none());
+        assert_eq!(proposal.status, ProposalStatus::Pending);
+        assert_eq!(proposal.abstain_votes, 0);
+        assert_eq!(proposal.no_with_veto_votes, 0);
+    }
+
+    #[test]
+    fn test_proposal_status_follows_voting_window() {
+        let contract = create_test_contract(mock_public_key(1), ContractId::from_bytes([2u8; 32]));
+        let proposal = Proposal {
+            start_height: 10,
+            end_height: 20,
+            yes_votes: 1,
+            ..Proposal::default()
+        };
+
+        assert_eq!(contract.status_at(&proposal, 0), ProposalStatus::Pending);
+        assert_eq!(contract.status_at(&proposal, 9), ProposalStatus::Pending);
+        assert_eq!(contract.status_at(&proposal, 10), ProposalStatus::Active);
+        assert_eq!(contract.status_at(&proposal, 19), ProposalStatus::Active);
+        assert_eq!(contract.status_at(&proposal, 20), ProposalStatus::Succeeded);
+    }
+
+    #[test]
+    fn test_finalized_status_is_not_derived() {
+        let contract = create_test_contract(mock_public_key(1), ContractId::from_bytes([2u8; 32]));
+        let proposal = Proposal {
+            start_height: 10,
+            end_height: 20,
+            status: ProposalStatus::Cancelled,
+            ..Proposal::default()
+        };
+
+        assert_eq!(contract.status_at(&proposal, 15), ProposalStatus::Cancelled);
+        assert_eq!(contract.status_at(&proposal, 25), ProposalStatus::Cancelled);
+    }
+
+    #[test]
+    fn test_finalized_statuses() {
+        assert!(!ProposalStatus::Pending.is_finalized());
+        assert!(!ProposalStatus::Active.is_finalized());
+        assert!(ProposalStatus::Succeeded.is_finalized());
+        assert!(ProposalStatus::Defeated.is_finalized());
+        assert!(ProposalStatus::Cancelled.is_finalized());
+    }
+
+    // ==================== Account Tests ====================
+
+    #[test]
+    fn test_account_external() {
+        let pk = mock_public_key(1);
+        let account = Account::External(pk);
+
+        match account {
+            Account::External(_) => (),
+            Account::Contract(_) => panic!("Expected External account"),
+        }
+    }
+
+    #[test]
+    fn test_account_contract() {
+        let contract_id = ContractId::from_bytes([1u8; 32]);
+        let account = Account::Contract(contract_id);
+
+        match account {
+            Account::Contract(id) => assert_eq!(id, contract_id),
+            Account::External(_) => panic!("Expected Contract account"),
+        }
+    }
+
+    #[test]
+    fn test_account_ordering() {
+        let pk1 = mock_public_key(1);
+        let pk2 = mock_public_key(2);
+        let contract_id = ContractId::from_bytes([1u8; 32]);
+
+        let ext1 = Account::External(pk1);
+        let ext2 = Account::External(pk2);
+        let contract = Account::Contract(contract_id);
+
+        // External accounts should come before contract accounts
+        assert!(ext1 < contract);
+        assert!(ext2 < contract);
+    }
+
+    // ==================== VoteContract State Tests ====================
+
+    #[test]
+    fn test_contract_init() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+
+        let mut contract = VoteContract {
+            state_version: 0,
+            admin: Account::Contract(ContractId::from_bytes([0u8; 32])),
+            pending_admin: None,
+            token_contract: ContractId::from_bytes([0u8; 32]),
+            voting_rules: VotingRules::DEFAULT,
+            voting_power_strategy: VotingPowerStrategy::DEFAULT,
+            proposals: BTreeMap::new(),
+            votes: BTreeMap::new(),
+            delegates: BTreeMap::new(),
+            delegators: BTreeMap::new(),
+            delegated_votes: BTreeMap::new(),
+            next_proposal_id: 0,
+            live_proposals: 0,
+            archived: BTreeMap::new(),
+            archive_age: 0,
+            execution_delay: 0,
+            grace_period: 0,
+            roles: BTreeMap::new(),
+            submission_policy: SubmissionPolicy {
+                permissionless: true,
+                ..SubmissionPolicy::ADMIN_ONLY
+            },
+            contract_voters: BTreeSet::from([ContractId::from_bytes([5u8; 32])]),
+            committee: None,
+            rounds: BTreeMap::new(),
+            next_round_id: 3,
+            credits_spent: BTreeMap::new(),
+            elections: BTreeMap::new(),
+            next_election_id: 2,
+            election_ballots: BTreeMap::new(),
+            operations: BTreeMap::new(),
+            next_operation_id: 0,
+            committee_executing: false,
+            paused: true,
+            successor: Some(ContractId::from_bytes([7u8; 32])),
+            predecessor: Some(ContractId::from_bytes([6u8; 32])),
+        };
+
+        contract.init(Account::External(admin_pk), token_id);
+
+        assert_eq!(contract.admin, Account::External(admin_pk));
+        assert_eq!(contract.token_contract, token_id);
+        assert!(contract.pending_admin.is_none());
+        assert_eq!(contract.next_proposal_id, 0);
+        assert_eq!(contract.next_round_id, 0);
+        assert_eq!(contract.next_election_id, 0);
+        assert_eq!(contract.archive_age, DEFAULT_ARCHIVE_AGE);
+        assert_eq!(contract.submission_policy, SubmissionPolicy::ADMIN_ONLY);
+        assert!(contract.contract_voters().is_empty());
+        assert!(Role::ALL.iter().all(|role| contract.has_role(*role, Account::External(admin_pk))));
+        assert!(!contract.is_paused());
+        assert_eq!(contract.successor(), None);
+        assert_eq!(contract.state_version(), STATE_VERSION);
+    }
+
+    #[test]
+    fn test_get_proposal_returns_none_for_nonexistent() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let contract = create_test_contract(admin_pk, token_id);
+
+        assert!(contract.get_proposal(0).is_none());
+        assert!(contract.get_proposal(999).is_none());
+    }
+
+    #[test]
+    fn test_proposal_v1_layout() {
+        let proposal = Proposal {
+            id: 3,
+            description: "Test".to_string(),
+            yes_votes: 10,
+            no_votes: 4,
+            abstain_votes: 5,
+            no_with_veto_votes: 2,
+            status: ProposalStatus::Defeated,
+            ..Proposal::default()
+        };
+        let v1 = ProposalV1::from(&proposal);
+        assert_eq!((v1.id, v1.description.as_str()), (3, "Test"));
+        assert_eq!((v1.yes_votes, v1.no_votes, v1.active), (10, 6, false));
+
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let contract = create_test_contract(admin_pk, token_id);
+        assert!(contract.get_proposal_v1(0).is_none());
+    }
+
+    #[test]
+    fn test_get_all_proposals_empty() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let contract = create_test_contract(admin_pk, token_id);
+
+        let proposals = contract.get_all_proposals();
+        assert!(proposals.is_empty());
+    }
+
+    #[test]
+    fn test_get_proposals_pages_newest_first() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        for _ in 0..5 {
+            contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        }
+
+        let ids = |proposals: Vec<Proposal>| proposals.iter().map(|p| p.id).collect::<Vec<_>>();
+        let filter = ProposalFilter::default();
+        assert_eq!(ids(contract.get_proposals(1, 2, filter.clone(), false)), vec![1, 2]);
+        assert_eq!(ids(contract.get_proposals(1, 2, filter.clone(), true)), vec![3, 2]);
+        assert_eq!(ids(contract.get_proposals(4, 10, filter.clone(), false)), vec![4]);
+        assert!(contract.get_proposals(5, 10, filter, false).is_empty());
+    }
+
+    #[test]
+    fn test_get_proposals_filters() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        contract
+            .add_proposal(NewProposal {
+                category: "treasury".to_string(),
+                ..new_proposal(10, 100, None)
+            })
+            .unwrap();
+
+        let by_category = ProposalFilter {
+            category: Some("treasury".to_string()),
+            ..ProposalFilter::default()
+        };
+        let proposals = contract.get_proposals(0, 10, by_category, false);
+        assert_eq!(proposals.len(), 1);
+        assert_eq!(proposals[0].id, 1);
+
+        let active = ProposalFilter {
+            status: Some(ProposalStatus::Active),
+            ..ProposalFilter::default()
+        };
+        let proposals = contract.get_proposals(0, 10, active, false);
+        assert_eq!(proposals.len(), 1);
+        assert_eq!(proposals[0].id, 0);
+
+        let other_creator = ProposalFilter {
+            creator: Some(Account::Contract(ContractId::from_bytes([9u8; 32]))),
+            ..ProposalFilter::default()
+        };
+        assert!(contract.get_proposals(0, 10, other_creator, false).is_empty());
+        assert_eq!(contract.proposals[&0].creator, Account::External(admin_pk));
+    }
+
+    #[test]
+    fn test_proposal_count_empty() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let contract = create_test_contract(admin_pk, token_id);
+
+        assert_eq!(contract.proposal_count(), 0);
+    }
+
+    #[test]
+    fn test_admin_getter() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let contract = create_test_contract(admin_pk, token_id);
+
+        assert_eq!(contract.admin(), Account::External(admin_pk));
+    }
+
+    #[test]
+    fn test_token_contract_getter() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let contract = create_test_contract(admin_pk, token_id);
+
+        assert_eq!(contract.token_contract(), token_id);
+    }
+
+    #[test]
+    fn test_pending_admin_initially_none() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let contract = create_test_contract(admin_pk, token_id);
+
+        assert!(contract.pending_admin().is_none());
+    }
+
+    // ==================== Voting Window Tests ====================
+
+    #[test]
+    fn test_add_proposal_records_window() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        let proposal = contract.get_proposal(id).unwrap();
+
+        assert_eq!(proposal.start_height, 0);
+        assert_eq!(proposal.end_height, 100);
+        assert_eq!(proposal.snapshot_height, 0);
+        assert_eq!(proposal.status, ProposalStatus::Active);
+        assert_eq!(contract.proposal_status(id), Some(ProposalStatus::Active));
+    }
+
+    #[test]
+    fn test_add_proposal_rejects_empty_window() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        assert_eq!(
+            contract.add_proposal(new_proposal(50, 50, None)),
+            Err(VoteError::InvalidVotingWindow)
+        );
+    }
+
+    // ==================== Lifecycle Tests ====================
+
+    #[test]
+    fn test_close_proposal_during_voting() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        assert_eq!(contract.close_proposal(id), Err(VoteError::VotingNotEnded));
+    }
+
+    #[test]
+    fn test_close_proposal_defeats_without_votes() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        end_voting(&mut contract, id);
+
+        assert_eq!(contract.close_proposal(id), Ok(ProposalOutcome::Rejected));
+        assert_eq!(contract.proposal_status(id), Some(ProposalStatus::Defeated));
+    }
+
+    #[test]
+    fn test_close_proposal_twice() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        end_voting(&mut contract, id);
+        contract.close_proposal(id).unwrap();
+        assert_eq!(contract.close_proposal(id), Err(VoteError::ProposalAlreadyFinalized));
+    }
+
+    #[test]
+    fn test_cancel_proposal() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        contract.cancel_proposal(id).unwrap();
+
+        assert_eq!(contract.proposal_status(id), Some(ProposalStatus::Cancelled));
+    }
+
+    #[test]
+    fn test_close_cancelled_proposal() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        contract.cancel_proposal(id).unwrap();
+        end_voting(&mut contract, id);
+        assert_eq!(contract.close_proposal(id), Err(VoteError::ProposalCancelled));
+    }
+
+    #[test]
+    fn test_cancel_finalized_proposal() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        end_voting(&mut contract, id);
+        contract.close_proposal(id).unwrap();
+        assert_eq!(contract.cancel_proposal(id), Err(VoteError::CannotCancelFinalized));
+    }
+
+    // ==================== Quorum and Threshold Tests ====================
+
+    #[test]
+    fn test_simple_majority_threshold() {
+        let threshold = Threshold::SIMPLE_MAJORITY;
+
+        assert!(threshold.is_met(51, 49));
+        assert!(!threshold.is_met(50, 50));
+        assert!(!threshold.is_met(0, 0));
+    }
+
+    #[test]
+    fn test_supermajority_threshold() {
+        let threshold = Threshold::SUPERMAJORITY;
+
+        assert!(threshold.is_met(67, 33));
+        assert!(!threshold.is_met(66, 34));
+        assert!(threshold.is_met(u64::MAX, 1));
+    }
+
+    #[test]
+    fn test_rules_evaluate_outcome() {
+        let rules = VotingRules {
+            quorum: Quorum::Absolute(100),
+            ..VotingRules::DEFAULT
+        };
+
+        assert_eq!(rules.evaluate(60, 39, 0, 0, 100), ProposalOutcome::QuorumNotMet);
+        assert_eq!(rules.evaluate(60, 40, 0, 0, 100), ProposalOutcome::Passed);
+        assert_eq!(rules.evaluate(40, 60, 0, 0, 100), ProposalOutcome::Rejected);
+    }
+
+    #[test]
+    fn test_abstain_counts_toward_quorum_only() {
+        let rules = VotingRules {
+            quorum: Quorum::Absolute(100),
+            ..VotingRules::DEFAULT
+        };
+
+        assert_eq!(rules.evaluate(10, 5, 85, 0, 100), ProposalOutcome::Passed);
+        assert_eq!(rules.evaluate(5, 10, 85, 0, 100), ProposalOutcome::Rejected);
+        assert_eq!(rules.evaluate(0, 0, 100, 0, 100), ProposalOutcome::Rejected);
+    }
+
+    #[test]
+    fn test_veto_overrides_yes_majority() {
+        let rules = VotingRules::DEFAULT;
+
+        // 34% veto defeats the proposal despite a yes majority
+        assert_eq!(rules.evaluate(66, 0, 0, 34, 0), ProposalOutcome::Vetoed);
+        // 33% veto is below the one-third share, but still counts against approval
+        assert_eq!(rules.evaluate(67, 0, 0, 33, 0), ProposalOutcome::Passed);
+        assert_eq!(rules.evaluate(30, 10, 30, 30, 0), ProposalOutcome::Rejected);
+    }
+
+    #[test]
+    fn test_close_proposal_records_outcome() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let rules = VotingRules {
+            quorum: Quorum::Absolute(1),
+            ..VotingRules::DEFAULT
+        };
+        let id = contract.add_proposal(new_proposal(0, 100, Some(rules))).unwrap();
+        end_voting(&mut contract, id);
+
+        assert_eq!(contract.close_proposal(id), Ok(ProposalOutcome::QuorumNotMet));
+        let proposal = contract.get_proposal(id).unwrap();
+        assert_eq!(proposal.rules, rules);
+        assert_eq!(proposal.outcome, Some(ProposalOutcome::QuorumNotMet));
+    }
+
+    #[test]
+    fn test_set_voting_rules_rejects_invalid_quorum() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        assert_eq!(
+            contract.set_voting_rules(VotingRules {
+                quorum: Quorum::SupplyBps(10_001),
+                ..VotingRules::DEFAULT
+            }),
+            Err(VoteError::InvalidQuorum)
+        );
+    }
+
+    // ==================== Voting Power Strategy Tests ====================
+
+    #[test]
+    fn test_integer_sqrt() {
+        for (n, root) in [(0, 0), (1, 1), (3, 1), (4, 2), (15, 3), (16, 4), (1_000_000, 1_000)] {
+            assert_eq!(integer_sqrt(n), root);
+        }
+        assert_eq!(integer_sqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(PowerTransform::SquareRoot.apply(99), 9);
+        assert_eq!(PowerTransform::Linear.apply(99), 99);
+    }
+
+    #[test]
+    fn test_strategy_validation() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let weight = |multiplier| TokenWeight { token: token_id, multiplier };
+        let multi_token = |tokens| VotingPowerStrategy {
+            source: PowerSource::MultiToken(tokens),
+            transform: PowerTransform::Linear,
+        };
+
+        for tokens in [Vec::new(), vec![weight(0)], vec![weight(1); MAX_STRATEGY_TOKENS + 1]] {
+            assert_eq!(
+                contract.set_voting_power_strategy(multi_token(tokens)),
+                Err(VoteError::InvalidStrategy)
+            );
+        }
+        contract.set_voting_power_strategy(multi_token(vec![weight(2)])).unwrap();
+        assert_eq!(contract.voting_power_strategy(), multi_token(vec![weight(2)]));
+
+        // Proposals keep the strategy they were created with
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        let staked = VotingPowerStrategy {
+            source: PowerSource::Staked,
+            transform: PowerTransform::SquareRoot,
+        };
+        let params = NewProposal { strategy: Some(staked.clone()), ..new_proposal(0, 100, None) };
+        let own = contract.add_proposal(params).unwrap();
+        contract.set_voting_power_strategy(VotingPowerStrategy::DEFAULT).unwrap();
+        assert_eq!(contract.get_proposal(id).unwrap().strategy, multi_token(vec![weight(2)]));
+        assert_eq!(contract.get_proposal(own).unwrap().strategy, staked);
+
+        // A supply-based quorum needs a supply to take a share of
+        let supply_quorum =
+            VotingRules { quorum: Quorum::SupplyBps(1_000), ..VotingRules::DEFAULT };
+        assert_eq!(
+            contract.add_proposal(NewProposal {
+                strategy: Some(staked),
+                ..new_proposal(0, 100, Some(supply_quorum))
+            }),
+            Err(VoteError::QuorumNeedsSupply)
+        );
+        contract.add_proposal(new_proposal(0, 100, Some(supply_quorum))).unwrap();
+    }
+
+    // ==================== Multi-Choice Tests ====================
+
+    #[test]
+    fn test_plurality_winner() {
+        assert_eq!(plurality_winner(&[10, 30, 20]), Some(1));
+        assert_eq!(plurality_winner(&[30, 30, 20]), None);
+        assert_eq!(plurality_winner(&[0, 0, 0]), None);
+    }
+
+    #[test]
+    fn test_add_multi_choice_proposal() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let id = contract.add_proposal(NewProposal {
+            options: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+            ..new_proposal(0, 100, None)
+        }).unwrap();
+        let proposal = contract.get_proposal(id).unwrap();
+
+        assert!(proposal.is_multi_choice());
+        assert_eq!(proposal.option_votes, vec![0, 0, 0]);
+        assert_eq!(proposal.winning_option, None);
+    }
+
+    #[test]
+    fn test_add_proposal_rejects_single_option() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        assert_eq!(
+            contract.add_proposal(NewProposal {
+                options: vec!["Only".to_string()],
+                ..new_proposal(0, 100, None)
+            }),
+            Err(VoteError::InvalidOptionCount)
+        );
+    }
+
+    #[test]
+    fn test_add_proposal_rejects_long_option_label() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        assert_eq!(
+            contract.add_proposal(NewProposal {
+                options: vec!["A".to_string(), "B".repeat(MAX_OPTION_LABEL_LEN + 1)],
+                ..new_proposal(0, 100, None)
+            }),
+            Err(VoteError::OptionLabelTooLong)
+        );
+    }
+
+    #[test]
+    fn test_standard_ballot_rejected_on_multi_choice() {
+        let proposal = Proposal {
+            options: vec!["A".to_string(), "B".to_string()],
+            option_votes: vec![0, 0],
+            ..Proposal::default()
+        };
+        assert_eq!(
+            proposal.check_choice(Choice::Standard(VoteOption::Yes)),
+            Err(VoteError::MultiChoiceProposal)
+        );
+    }
+
+    #[test]
+    fn test_option_index_out_of_range() {
+        let proposal = Proposal {
+            options: vec!["A".to_string(), "B".to_string()],
+            option_votes: vec![0, 0],
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.check_choice(Choice::MultiChoice(2)), Err(VoteError::InvalidOption));
+        assert_eq!(proposal.check_choice(Choice::MultiChoice(1)), Ok(()));
+    }
+
+    // ==================== Vote Change Tests ====================
+
+    /// Record a ballot for the mock sender without querying the token contract
+    fn seed_ballot(contract: &mut VoteContract, proposal_id: u32, option: VoteOption, weight: u64) {
+        let choice = Choice::Standard(option);
+        let proposal = contract.proposals.get_mut(&proposal_id).unwrap();
+        *proposal.tally_mut(choice) += weight;
+        contract.votes.get_mut(&proposal_id).unwrap()
+            .insert(sender_account().unwrap(), Ballot { choice, weight });
+    }
+
+    #[test]
+    fn test_change_vote_moves_weight() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        seed_ballot(&mut contract, id, VoteOption::Yes, 40);
+
+        contract.change_vote(id, Choice::Standard(VoteOption::NoWithVeto)).unwrap();
+        let proposal = contract.get_proposal(id).unwrap();
+        assert_eq!(proposal.yes_votes, 0);
+        assert_eq!(proposal.no_with_veto_votes, 40);
+        assert_eq!(
+            contract.get_ballot(sender_account().unwrap(), id).unwrap().choice,
+            Choice::Standard(VoteOption::NoWithVeto)
+        );
+    }
+
+    #[test]
+    fn test_retract_vote_clears_ballot() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        seed_ballot(&mut contract, id, VoteOption::No, 25);
+
+        contract.retract_vote(id).unwrap();
+        assert_eq!(contract.get_proposal(id).unwrap().no_votes, 0);
+        assert!(contract.get_ballot(sender_account().unwrap(), id).is_none());
+    }
+
+    #[test]
+    fn test_retract_vote_without_ballot() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        assert_eq!(contract.retract_vote(id), Err(VoteError::NotVoted));
+    }
+
+    #[test]
+    fn test_change_vote_rejects_tally_overflow() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        seed_ballot(&mut contract, id, VoteOption::Yes, 10);
+        contract.proposals.get_mut(&id).unwrap().no_votes = u64::MAX - 5;
+
+        let no = Choice::Standard(VoteOption::No);
+        assert_eq!(contract.change_vote(id, no), Err(VoteError::TallyOverflow));
+        let proposal = contract.get_proposal(id).unwrap();
+        assert_eq!((proposal.yes_votes, proposal.no_votes), (10, u64::MAX - 5));
+    }
+
+    // ==================== Quadratic Voting Tests ====================
+
+    fn new_round(start_height: u64, end_height: u64) -> NewRound {
+        NewRound { description: "Round".to_string(), start_height, end_height }
+    }
+
+    #[test]
+    fn test_quadratic_cost() {
+        assert_eq!(quadratic_cost(0), Ok(0));
+        assert_eq!(quadratic_cost(3), Ok(9));
+        assert_eq!(quadratic_cost(u32::MAX as u64), Ok((u32::MAX as u64).pow(2)));
+        assert_eq!(quadratic_cost(1 << 32), Err(VoteError::TallyOverflow));
+    }
+
+    #[test]
+    fn test_quadratic_votes() {
+        assert_eq!(quadratic_votes(0), 0);
+        assert_eq!(quadratic_votes(3), 1);
+        assert_eq!(quadratic_votes(850), 29);
+        assert_eq!(quadratic_votes(900), 30);
+        assert_eq!(quadratic_votes(u64::MAX), u32::MAX as u64);
+    }
+
+    #[test]
+    fn test_round_proposals() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        assert_eq!(contract.create_round(new_round(0, 100)), Err(VoteError::VotingWindowInPast));
+        let round_id = contract.create_round(new_round(1, 100)).unwrap();
+
+        let joining =
+            |start, end| NewProposal { round: Some(round_id), ..new_proposal(start, end, None) };
+        assert_eq!(
+            contract.add_proposal(NewProposal { round: Some(9), ..new_proposal(1, 100, None) }),
+            Err(VoteError::RoundNotFound)
+        );
+        assert_eq!(contract.add_proposal(joining(1, 50)), Err(VoteError::InvalidVotingWindow));
+        let multi_choice = NewProposal {
+            options: vec!["A".to_string(), "B".to_string()],
+            ..joining(1, 100)
+        };
+        assert_eq!(contract.add_proposal(multi_choice), Err(VoteError::RoundProposal));
+        let own_strategy = NewProposal {
+            strategy: Some(VotingPowerStrategy::DEFAULT),
+            ..joining(1, 100)
+        };
+        assert_eq!(contract.add_proposal(own_strategy), Err(VoteError::InvalidStrategy));
+
+        // The global strategy has no supply, but round proposals use the default
+        let registry = PowerSource::Registry(ContractId::from_bytes([4u8; 32]));
+        contract.voting_power_strategy.source = registry;
+        let rules = VotingRules { quorum: Quorum::SupplyBps(1_000), ..VotingRules::DEFAULT };
+        let supply_quorum = NewProposal { rules: Some(rules), ..joining(1, 100) };
+        let id = contract.add_proposal(supply_quorum).unwrap();
+        let round = contract.get_round(round_id).unwrap();
+        assert_eq!(round.proposal_ids, vec![id]);
+        assert_eq!(contract.get_proposal(id).unwrap().round, Some(round_id));
+        assert_eq!(contract.get_proposal(id).unwrap().strategy, VotingPowerStrategy::DEFAULT);
+
+        // Round proposals only take quadratic votes, which need credits
+        contract.proposals.get_mut(&id).unwrap().start_height = 0;
+        assert_eq!(contract.vote(id, true), Err(VoteError::RoundProposal));
+        assert_eq!(contract.retract_vote(id), Err(VoteError::RoundProposal));
+        assert_eq!(contract.vote_quadratic(id, true, 2), Err(VoteError::NoVotingPower));
+        assert_eq!(contract.remaining_credits(sender_account().unwrap(), round_id), 0);
+
+        let regular = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        assert_eq!(contract.vote_quadratic(regular, true, 2), Err(VoteError::NotRoundProposal));
+
+        contract.rounds.get_mut(&round_id).unwrap().start_height = 0;
+        assert_eq!(contract.add_proposal(joining(0, 100)), Err(VoteError::RoundStarted));
+    }
+
+    // ==================== Election Tests ====================
+
+    fn ranked(ranking: &[u8], weight: u64) -> RankedBallot {
+        RankedBallot { ranking: ranking.to_vec(), weight }
+    }
+
+    fn new_election(candidates: Vec<Account>, seats: u32) -> NewElection {
+        NewElection {
+            description: "Council".to_string(),
+            start_height: 0,
+            end_height: 100,
+            candidates,
+            seats,
+            install_council: true,
+        }
+    }
+
+    fn candidate(seed: u8) -> Account {
+        Account::Contract(ContractId::from_bytes([seed; 32]))
+    }
+
+    #[test]
+    fn test_instant_runoff_transfers_eliminated_ballots() {
+        // Candidate 0 leads the first count, but candidate 1's voters prefer 2
+        let ballots = [ranked(&[0], 500), ranked(&[2, 1], 350), ranked(&[1, 2], 200)];
+        assert_eq!(count_stv(3, &ballots, 1), vec![2]);
+
+        // A majority of first preferences wins outright
+        let ballots = [ranked(&[0], 600), ranked(&[2, 1], 350), ranked(&[1, 2], 200)];
+        assert_eq!(count_stv(3, &ballots, 1), vec![0]);
+    }
+
+    #[test]
+    fn test_stv_transfers_surplus() {
+        // Quota is 334: candidate 0's surplus of 166 lifts candidate 1 past candidate 2
+        let ballots = [ranked(&[0, 1], 500), ranked(&[2, 0], 300), ranked(&[1, 0], 200)];
+        assert_eq!(count_stv(3, &ballots, 2), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_stv_fills_seats_from_continuing_candidates() {
+        // The surplus is exhausted, so the remaining seat goes to the earlier of two tied
+        let ballots = [ranked(&[0], 100)];
+        assert_eq!(count_stv(3, &ballots, 2), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_create_election_validation() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let duplicate = new_election(vec![candidate(5), candidate(5)], 1);
+        assert_eq!(contract.create_election(duplicate), Err(VoteError::InvalidCandidates));
+        let single = new_election(vec![candidate(5)], 1);
+        assert_eq!(contract.create_election(single), Err(VoteError::InvalidCandidates));
+        for seats in [0, 2] {
+            let election = new_election(vec![candidate(5), candidate(6)], seats);
+            assert_eq!(contract.create_election(election), Err(VoteError::InvalidSeats));
+        }
+
+        let pair = vec![candidate(5), candidate(6)];
+        let id = contract.create_election(new_election(pair.clone(), 1)).unwrap();
+        assert_eq!(contract.get_election(id).unwrap().candidates, pair);
+        assert_eq!(contract.vote_ranked(9, vec![0]), Err(VoteError::ElectionNotFound));
+        for ranking in [vec![], vec![0, 0], vec![2]] {
+            assert_eq!(contract.vote_ranked(id, ranking), Err(VoteError::InvalidRanking));
+        }
+        assert_eq!(contract.vote_ranked(id, vec![1, 0]), Err(VoteError::NoVotingPower));
+    }
+
+    #[test]
+    fn test_finalize_election_installs_council() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let candidates = vec![candidate(5), candidate(6), candidate(7)];
+        contract.grant_role(Role::Council, candidate(7)).unwrap();
+
+        let id = contract.create_election(new_election(candidates, 2)).unwrap();
+        let ballots = contract.election_ballots.get_mut(&id).unwrap();
+        ballots.insert(candidate(8), ranked(&[0, 1], 500));
+        ballots.insert(candidate(9), ranked(&[2, 0], 300));
+        ballots.insert(candidate(10), ranked(&[1, 0], 200));
+        assert_eq!(contract.finalize_election(id), Err(VoteError::VotingNotEnded));
+
+        contract.elections.get_mut(&id).unwrap().end_height = 0;
+        assert_eq!(contract.finalize_election(id), Ok(vec![candidate(5), candidate(6)]));
+        assert_eq!(contract.role_members(Role::Council), vec![candidate(5), candidate(6)]);
+        assert_eq!(contract.finalize_election(id), Err(VoteError::ElectionFinalized));
+    }
+
+    // ==================== Delegation Tests ====================
+    // Contract accounts stand in for distinct voters, as mock public keys all
+    // collapse to the default key
+
+    /// Record a delegation for an arbitrary delegator
+    fn link(contract: &mut VoteContract, from: Account, to: Account, scope: DelegationScope) {
+        contract.delegates.insert((from, scope.clone()), to);
+        contract.delegators.entry((to, scope)).or_default().insert(from);
+    }
+
+    #[test]
+    fn test_delegate_and_undelegate() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let delegate = Account::Contract(ContractId::from_bytes([2u8; 32]));
+        let global = DelegationScope::Global;
+        let sender = sender_account().unwrap();
+
+        contract.delegate(delegate).unwrap();
+        assert_eq!(contract.delegate_of(sender, global.clone()), Some(delegate));
+        assert_eq!(contract.delegators_of(delegate, global.clone()), vec![sender]);
+
+        // Re-delegating moves the caller to the new delegate's list
+        let other = Account::Contract(ContractId::from_bytes([3u8; 32]));
+        contract.delegate(other).unwrap();
+        assert!(contract.delegators_of(delegate, global.clone()).is_empty());
+        assert_eq!(contract.delegators_of(other, global.clone()), vec![sender]);
+
+        contract.undelegate().unwrap();
+        assert_eq!(contract.delegate_of(sender, global.clone()), None);
+        assert!(contract.delegators_of(other, global).is_empty());
+    }
+
+    #[test]
+    fn test_delegate_rejects_cycle() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let a = Account::Contract(ContractId::from_bytes([2u8; 32]));
+        let b = Account::Contract(ContractId::from_bytes([3u8; 32]));
+
+        // b -> a -> caller already exists, so caller -> b would close the loop
+        link(&mut contract, a, sender_account().unwrap(), DelegationScope::Global);
+        link(&mut contract, b, a, DelegationScope::Global);
+        assert_eq!(contract.delegate(b), Err(VoteError::DelegationCycle));
+    }
+
+    #[test]
+    fn test_category_delegation_rejects_cycle_through_global() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let expert = Account::Contract(ContractId::from_bytes([2u8; 32]));
+
+        // The expert delegates everything to the caller, so within the
+        // category the caller -> expert edge would loop
+        link(&mut contract, expert, sender_account().unwrap(), DelegationScope::Global);
+        let treasury = DelegationScope::Category("treasury".to_string());
+        assert_eq!(contract.delegate_scoped(treasury, expert), Err(VoteError::DelegationCycle));
+    }
+
+    #[test]
+    fn test_global_delegation_rejects_cycle_through_category() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let expert = Account::Contract(ContractId::from_bytes([2u8; 32]));
+        let other = Account::Contract(ContractId::from_bytes([3u8; 32]));
+        let treasury = DelegationScope::Category("treasury".to_string());
+
+        // Within the category the expert delegates to the caller, and a global
+        // caller -> expert edge would apply there too
+        link(&mut contract, expert, sender_account().unwrap(), treasury.clone());
+        assert_eq!(contract.delegate(expert), Err(VoteError::DelegationCycle));
+
+        // Once the caller delegates the category elsewhere the global edge no longer loops
+        contract.delegate_scoped(treasury, other).unwrap();
+        contract.delegate(expert).unwrap();
+    }
+
+    #[test]
+    fn test_delegate_rejects_self() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let sender = sender_account().unwrap();
+        assert_eq!(contract.delegate(sender), Err(VoteError::CannotDelegateSelf));
+    }
+
+    #[test]
+    fn test_delegated_accounts_skip_direct_voters() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let root = Account::Contract(ContractId::from_bytes([2u8; 32]));
+        let voter = Account::Contract(ContractId::from_bytes([3u8; 32]));
+        let below_voter = Account::Contract(ContractId::from_bytes([4u8; 32]));
+
+        // below_voter -> voter -> root, where voter has cast their own ballot
+        link(&mut contract, voter, root, DelegationScope::Global);
+        link(&mut contract, below_voter, voter, DelegationScope::Global);
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        assert_eq!(contract.delegated_accounts(id, root), vec![voter, below_voter]);
+
+        contract.votes.get_mut(&id).unwrap().insert(
+            voter,
+            Ballot { choice: Choice::Standard(VoteOption::Yes), weight: 0 },
+        );
+        assert!(contract.delegated_accounts(id, root).is_empty());
+    }
+
+    #[test]
+    fn test_most_specific_delegation_wins() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let holder = Account::Contract(ContractId::from_bytes([2u8; 32]));
+        let generalist = Account::Contract(ContractId::from_bytes([3u8; 32]));
+        let expert = Account::Contract(ContractId::from_bytes([4u8; 32]));
+        let stand_in = Account::Contract(ContractId::from_bytes([5u8; 32]));
+
+        let general = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        let treasury = contract.add_proposal(NewProposal {
+            category: "treasury".to_string(),
+            ..new_proposal(0, 100, None)
+        }).unwrap();
+        let overridden = contract.add_proposal(NewProposal {
+            category: "treasury".to_string(),
+            ..new_proposal(0, 100, None)
+        }).unwrap();
+        link(&mut contract, holder, generalist, DelegationScope::Global);
+        link(&mut contract, holder, expert, DelegationScope::Category("treasury".to_string()));
+        link(&mut contract, holder, stand_in, DelegationScope::Proposal(overridden));
+
+        assert_eq!(contract.effective_delegate(holder, general), Some(generalist));
+        assert_eq!(contract.effective_delegate(holder, treasury), Some(expert));
+        assert_eq!(contract.effective_delegate(holder, overridden), Some(stand_in));
+
+        // Weight only flows along the delegation that applies
+        assert!(contract.delegated_accounts(treasury, generalist).is_empty());
+        assert_eq!(contract.delegated_accounts(treasury, expert), vec![holder]);
+        assert!(contract.delegated_accounts(overridden, expert).is_empty());
+    }
+
+    // ==================== Has Account Voted Tests ====================
+
+    #[test]
+    fn test_has_account_voted_false_no_proposal() {
+        let admin_pk = mock_public_key(1);
+        let voter_pk = mock_public_key(2);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let contract = create_test_contract(admin_pk, token_id);
+
+        // No proposal exists, should return false
+        assert!(!contract.has_account_voted(voter_pk, 0));
+    }
+
+    #[test]
+    fn test_get_account_vote_weight_zero_no_vote() {
+        let admin_pk = mock_public_key(1);
+        let voter_pk = mock_public_key(2);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let contract = create_test_contract(admin_pk, token_id);
+
+        // No vote cast, should return 0
+        assert_eq!(contract.get_account_vote_weight(voter_pk, 0), 0);
+    }
+
+    // ==================== Constants Tests ====================
+
+    #[test]
+    fn test_max_proposal_description_length() {
+        assert_eq!(MAX_PROPOSAL_DESC_LEN, 256);
+    }
+
+    #[test]
+    fn test_max_proposals() {
+        assert_eq!(MAX_PROPOSALS, 100);
+    }
+
+    // ==================== Execution Tests ====================
 
-/// Get token balance for a public key
-#[no_mangle]
-pub unsafe fn get_balance(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |public_key: PublicKey| STATE.get_balance(public_key))
-}
+    fn action(function: &str) -> Action {
+        Action {
+            target: ContractId::from_bytes([3u8; 32]),
+            function: function.to_string(),
+            args: Vec::new(),
+        }
+    }
 
-/// Get the token contract ID used for voting weight
-#[no_mangle]
-pub unsafe fn token_contract(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |_: ()| STATE.token_contract())
-}
+    /// Add a proposal with two actions and record a passing outcome
+    fn passed_proposal_with_actions(contract: &mut VoteContract) -> u32 {
+        let id = contract
+            .add_proposal(NewProposal {
+                actions: vec![action("first"), action("second")],
+                ..new_proposal(0, 100, None)
+            })
+            .unwrap();
+        seed_ballot(contract, id, VoteOption::Yes, 1000);
+        end_voting(contract, id);
+        assert_eq!(contract.close_proposal(id), Ok(ProposalOutcome::Passed));
+        id
+    }
 
-/// Get admin account
-#[no_mangle]
-pub unsafe fn admin(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |_: ()| STATE.admin())
-}
+    #[test]
+    fn test_queue_then_execute_after_timelock() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let id = passed_proposal_with_actions(&mut contract);
 
-/// Check if caller is admin
-#[no_mangle]
-pub unsafe fn is_admin(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |_: ()| STATE.is_admin())
-}
+        // Succeeded proposals must be queued first
+        assert_eq!(contract.execute(id), Err(VoteError::NotQueued));
+        contract.queue(id).unwrap();
+        assert_eq!(contract.queue(id), Err(VoteError::NotExecutable));
+        let proposal = &contract.proposals[&id];
+        assert_eq!(proposal.status, ProposalStatus::Queued);
+        assert_eq!(proposal.execute_after, DEFAULT_EXECUTION_DELAY);
+        assert_eq!(proposal.expires_at, DEFAULT_EXECUTION_DELAY + DEFAULT_GRACE_PERIOD);
+        assert_eq!(contract.execute(id), Err(VoteError::TimelockNotElapsed));
 
-// ==================== Admin Transfer Entry Points ====================
+        // Move the timelock into the past (block height is fixed at 0 outside the VM)
+        contract.proposals.get_mut(&id).unwrap().execute_after = 0;
+        let results = contract.execute(id).unwrap();
+        assert_eq!(results, vec![ActionResult::Success(Vec::new()); 2]);
+        assert_eq!(contract.proposals[&id].action_results, results);
+        assert_eq!(contract.proposals[&id].status, ProposalStatus::Executed);
+        assert_eq!(contract.execute(id), Err(VoteError::AlreadyExecuted));
+    }
 
-/// Propose a new admin (admin only) - two-step transfer process
-#[no_mangle]
-pub unsafe fn propose_admin(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |new_admin: Account| {
-        STATE.propose_admin(new_admin)
-    })
-}
+    #[test]
+    fn test_queued_proposal_expires_after_grace_period() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        contract.set_timelock(0, 0).unwrap();
+        let id = passed_proposal_with_actions(&mut contract);
+        contract.queue(id).unwrap();
 
-/// Accept admin role (pending admin only) - completes the transfer
-#[no_mangle]
-pub unsafe fn accept_admin(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |_: ()| STATE.accept_admin())
-}
+        assert_eq!(contract.execute(id), Err(VoteError::ProposalExpired));
+        assert_eq!(contract.proposal_status(id), Some(ProposalStatus::Expired));
+    }
 
-/// Cancel pending admin transfer (admin only)
-#[no_mangle]
-pub unsafe fn cancel_admin_proposal(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |_: ()| STATE.cancel_admin_proposal())
-}
+    #[test]
+    fn test_canceller_cancels_during_delay() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let id = passed_proposal_with_actions(&mut contract);
+        contract.queue(id).unwrap();
 
-/// Get pending admin (if any)
-#[no_mangle]
-pub unsafe fn pending_admin(arg_len: u32) -> u32 {
-    abi::wrap_call(arg_len, |_: ()| STATE.pending_admin())
-}
+        let admin = Account::External(admin_pk);
+        contract.revoke_role(Role::Canceller, admin).unwrap();
+        assert_eq!(contract.cancel_queued(id), Err(VoteError::MissingRole));
+        contract.grant_role(Role::Canceller, admin).unwrap();
 
-} // end of entry_points module
+        // Regular cancellation does not reach past the outcome
+        assert_eq!(contract.cancel_proposal(id), Err(VoteError::CannotCancelFinalized));
+        contract.cancel_queued(id).unwrap();
+        assert_eq!(contract.proposals[&id].status, ProposalStatus::Cancelled);
+        assert_eq!(contract.execute(id), Err(VoteError::NotQueued));
+    }
 
-// ==================== Unit Tests ====================
-// These tests verify the contract logic without requiring the VM
+    #[test]
+    fn test_canceller_cannot_cancel_after_delay() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        contract.set_timelock(0, 100).unwrap();
+        let id = passed_proposal_with_actions(&mut contract);
+        contract.queue(id).unwrap();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use alloc::string::ToString;
+        assert_eq!(contract.cancel_queued(id), Err(VoteError::TimelockElapsed));
+    }
 
-    fn create_test_contract(admin_pk: PublicKey, token_contract: ContractId) -> VoteContract {
-        VoteContract {
-            admin: Account::External(admin_pk),
-            pending_admin: None,
-            token_contract,
-            proposals: Vec::new(),
-            votes: BTreeMap::new(),
-            next_proposal_id: 0,
-        }
+    #[test]
+    fn test_archive_waits_for_pending_actions() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let id = passed_proposal_with_actions(&mut contract);
+        contract.set_archive_age(0).unwrap();
+
+        // Neither the unqueued nor the queued actions may be dropped
+        assert_eq!(contract.archive_proposals(10), 0);
+        contract.queue(id).unwrap();
+        assert_eq!(contract.archive_proposals(10), 0);
+
+        // Once the grace period lapses the actions can never run
+        contract.proposals.get_mut(&id).unwrap().expires_at = 0;
+        assert_eq!(contract.archive_proposals(10), 1);
+        assert_eq!(contract.proposal_status(id), Some(ProposalStatus::Expired));
     }
 
-    fn mock_public_key(seed: u8) -> PublicKey {
-        // Create a deterministic but valid-looking public key for tests
-        let mut bytes = [0u8; 96];
-        for i in 0..96 {
-            bytes[i] = seed.wrapping_add(i as u8);
+    #[test]
+    fn test_archive_frees_round_credits_with_last_proposal() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let round_id = contract.create_round(new_round(1, 100)).unwrap();
+        let joining = NewProposal { round: Some(round_id), ..new_proposal(1, 100, None) };
+        let first = contract.add_proposal(joining.clone()).unwrap();
+        let second = contract.add_proposal(joining).unwrap();
+        let voter = sender_account().unwrap();
+        contract.credits_spent.insert((round_id, voter), 9);
+        for id in [first, second] {
+            end_voting(&mut contract, id);
+            contract.close_proposal(id).unwrap();
         }
-        // Note: This won't create a cryptographically valid key,
-        // but it's sufficient for testing data structures
-        PublicKey::default()
+        contract.set_archive_age(0).unwrap();
+
+        // The other proposal still accounts for the spent credits
+        assert_eq!(contract.archive_proposals(1), 1);
+        assert_eq!(contract.credits_spent.get(&(round_id, voter)), Some(&9));
+        assert_eq!(contract.archive_proposals(1), 1);
+        assert!(contract.credits_spent.is_empty());
     }
 
-    // ==================== Proposal Tests ====================
+    #[test]
+    fn test_proposal_without_actions_cannot_be_queued() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        seed_ballot(&mut contract, id, VoteOption::Yes, 1000);
+        end_voting(&mut contract, id);
+        contract.close_proposal(id).unwrap();
+
+        assert_eq!(contract.queue(id), Err(VoteError::NotExecutable));
+    }
 
     #[test]
-    fn test_proposal_structure() {
-        let proposal = Proposal {
-            id: 0,
-            description: "Test proposal".to_string(),
-            yes_votes: 100,
-            no_votes: 50,
-            active: true,
+    fn test_action_limits() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+
+        let too_many = NewProposal {
+            actions: vec![action("call"); MAX_ACTIONS + 1],
+            ..new_proposal(0, 100, None)
         };
+        assert_eq!(contract.add_proposal(too_many), Err(VoteError::TooManyActions));
 
-        assert_eq!(proposal.id, 0);
-        assert_eq!(proposal.description, "Test proposal");
-        assert_eq!(proposal.yes_votes, 100);
-        assert_eq!(proposal.no_votes, 50);
-        assert!(proposal.active);
+        let mut large = action("call");
+        large.args = vec![0u8; MAX_ACTION_ARGS_LEN + 1];
+        let too_large = NewProposal { actions: vec![large], ..new_proposal(0, 100, None) };
+        assert_eq!(contract.add_proposal(too_large), Err(VoteError::ActionTooLarge));
     }
 
     #[test]
-    fn test_proposal_default() {
-        let proposal = Proposal::default();
+    fn test_actions_cannot_target_vote_contract() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
 
-        assert_eq!(proposal.id, 0);
-        assert_eq!(proposal.description, "");
-        assert_eq!(proposal.yes_votes, 0);
-        assert_eq!(proposal.no_votes, 0);
-        assert!(!proposal.active);
+        // A call to this contract would act as the committee and hold every role
+        let mut grant = action("grant_role");
+        let Account::Contract(self_id) = committee_account() else { unreachable!() };
+        grant.target = self_id;
+        let actions = vec![action("first"), grant];
+        let params = NewProposal { actions, ..new_proposal(0, 100, None) };
+        assert_eq!(contract.add_proposal(params), Err(VoteError::SelfTargetingAction));
+        assert!(contract.proposals.is_empty());
     }
 
-    // ==================== Account Tests ====================
+    // ==================== Role Tests ====================
 
     #[test]
-    fn test_account_external() {
-        let pk = mock_public_key(1);
-        let account = Account::External(pk);
+    fn test_grant_and_revoke_roles() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let admin = Account::External(admin_pk);
+        let closer = Account::Contract(ContractId::from_bytes([5u8; 32]));
 
-        match account {
-            Account::External(_) => (),
-            Account::Contract(_) => panic!("Expected External account"),
-        }
+        contract.grant_role(Role::Closer, closer).unwrap();
+        assert!(contract.has_role(Role::Closer, closer));
+        assert!(!contract.has_role(Role::Canceller, closer));
+        assert_eq!(contract.role_members(Role::Closer).len(), 2);
+
+        // Roles are independent: losing Closer does not affect cancelling
+        contract.revoke_role(Role::Closer, admin).unwrap();
+        assert_eq!(contract.revoke_role(Role::Closer, admin), Err(VoteError::MissingRole));
+        assert_eq!(contract.role_members(Role::Closer), vec![closer]);
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        end_voting(&mut contract, id);
+        assert_eq!(contract.close_proposal(id), Err(VoteError::MissingRole));
+        assert_eq!(contract.cancel_proposal(id), Ok(()));
     }
 
     #[test]
-    fn test_account_contract() {
-        let contract_id = ContractId::from_bytes([1u8; 32]);
-        let account = Account::Contract(contract_id);
+    fn test_only_admin_manages_roles() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        contract.admin = Account::Contract(ContractId::from_bytes([9u8; 32]));
+        let account = Account::Contract(ContractId::from_bytes([5u8; 32]));
 
-        match account {
-            Account::Contract(id) => assert_eq!(id, contract_id),
-            Account::External(_) => panic!("Expected Contract account"),
-        }
+        // Roles held by the caller do not include managing roles
+        assert_eq!(contract.grant_role(Role::Pauser, account), Err(VoteError::NotAdmin));
+        assert_eq!(
+            contract.revoke_role(Role::Pauser, Account::External(admin_pk)),
+            Err(VoteError::NotAdmin)
+        );
+        assert_eq!(contract.set_timelock(0, 0), Ok(()));
     }
 
+    // ==================== Contract Voter Tests ====================
+
     #[test]
-    fn test_account_ordering() {
-        let pk1 = mock_public_key(1);
-        let pk2 = mock_public_key(2);
-        let contract_id = ContractId::from_bytes([1u8; 32]);
+    fn test_contract_voter_allowlist() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let vault = ContractId::from_bytes([5u8; 32]);
 
-        let ext1 = Account::External(pk1);
-        let ext2 = Account::External(pk2);
-        let contract = Account::Contract(contract_id);
+        assert!(!contract.is_contract_voter(vault));
+        contract.allow_contract_voter(vault).unwrap();
+        contract.allow_contract_voter(vault).unwrap();
+        assert!(contract.is_contract_voter(vault));
+        assert_eq!(contract.contract_voters(), vec![vault]);
 
-        // External accounts should come before contract accounts
-        assert!(ext1 < contract);
-        assert!(ext2 < contract);
+        contract.remove_contract_voter(vault).unwrap();
+        assert_eq!(contract.remove_contract_voter(vault), Err(VoteError::NotContractVoter));
+        assert!(contract.contract_voters().is_empty());
+
+        contract.revoke_role(Role::ConfigManager, Account::External(admin_pk)).unwrap();
+        assert_eq!(contract.allow_contract_voter(vault), Err(VoteError::MissingRole));
     }
 
-    // ==================== VoteContract State Tests ====================
+    // ==================== Committee Tests ====================
 
     #[test]
-    fn test_contract_init() {
+    fn test_committee_validation() {
         let admin_pk = mock_public_key(1);
         let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let committee = |members: Vec<PublicKey>, threshold| Committee { members, threshold };
 
-        let mut contract = VoteContract {
-            admin: Account::Contract(ContractId::from_bytes([0u8; 32])),
-            pending_admin: None,
-            token_contract: ContractId::from_bytes([0u8; 32]),
-            proposals: Vec::new(),
-            votes: BTreeMap::new(),
-            next_proposal_id: 0,
-        };
+        for invalid in [
+            committee(Vec::new(), 0),
+            committee(vec![admin_pk], 0),
+            committee(vec![admin_pk], 2),
+            committee(vec![admin_pk, admin_pk], 1),
+            committee(vec![admin_pk; MAX_COMMITTEE_MEMBERS + 1], 1),
+        ] {
+            assert_eq!(contract.set_committee(invalid), Err(VoteError::InvalidCommittee));
+        }
+        assert_eq!(
+            contract.submit_operation(AdminOperation::CloseProposal(0)),
+            Err(VoteError::NotCommitteeMember)
+        );
+    }
 
-        contract.init(Account::External(admin_pk), token_id);
+    #[test]
+    fn test_committee_takes_over_admin() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let committee = Committee { members: vec![admin_pk], threshold: 1 };
+        contract.set_committee(committee.clone()).unwrap();
 
-        assert_eq!(contract.admin, Account::External(admin_pk));
-        assert_eq!(contract.token_contract, token_id);
-        assert!(contract.pending_admin.is_none());
-        assert_eq!(contract.next_proposal_id, 0);
+        // The key alone is no longer the admin and holds no roles
+        let admin = Account::External(admin_pk);
+        assert_eq!(contract.admin, committee_account());
+        assert!(Role::ALL.iter().all(|role| !contract.has_role(*role, admin)));
+        assert!(Role::ALL.iter().all(|role| contract.has_role(*role, committee_account())));
+        assert_eq!(contract.add_proposal(new_proposal(0, 100, None)), Err(VoteError::MissingRole));
+        assert_eq!(contract.committee(), Some(committee));
+
+        // A one-of-one committee carries out operations on submission
+        let operation = AdminOperation::AddProposal(new_proposal(0, 100, None));
+        assert_eq!(contract.submit_operation(operation), Ok(0));
+        assert_eq!(contract.proposals[&0].creator, committee_account());
+        assert!(contract.pending_operations().is_empty());
+        assert!(!contract.committee_executing);
     }
 
     #[test]
-    fn test_get_proposal_returns_none_for_nonexistent() {
+    fn test_failed_operation_stays_pending() {
         let admin_pk = mock_public_key(1);
         let token_id = ContractId::from_bytes([2u8; 32]);
-        let contract = create_test_contract(admin_pk, token_id);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        contract.set_committee(Committee { members: vec![admin_pk], threshold: 1 }).unwrap();
 
-        assert!(contract.get_proposal(0).is_none());
-        assert!(contract.get_proposal(999).is_none());
+        assert_eq!(
+            contract.submit_operation(AdminOperation::CloseProposal(7)),
+            Err(VoteError::ProposalNotFound)
+        );
+        assert!(contract.pending_operations().is_empty());
+        assert_eq!(contract.next_operation_id, 0);
+
+        // Operations waiting for more approvals can be withdrawn by their submitter
+        contract.committee.as_mut().unwrap().threshold = 2;
+        let id = contract.submit_operation(AdminOperation::SetArchiveAge(10)).unwrap();
+        assert_eq!(contract.get_operation(id).unwrap().approvals, vec![admin_pk]);
+        assert_eq!(contract.approve_operation(id), Err(VoteError::AlreadyApproved));
+        contract.cancel_operation(id).unwrap();
+        assert_eq!(contract.approve_operation(id), Err(VoteError::OperationNotFound));
+        assert_eq!(contract.archive_age, DEFAULT_ARCHIVE_AGE);
     }
 
+    // ==================== Pause Tests ====================
+
     #[test]
-    fn test_get_all_proposals_empty() {
+    fn test_pause_blocks_voting_and_proposals() {
         let admin_pk = mock_public_key(1);
         let token_id = ContractId::from_bytes([2u8; 32]);
-        let contract = create_test_contract(admin_pk, token_id);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
 
-        let proposals = contract.get_all_proposals();
-        assert!(proposals.is_empty());
+        assert_eq!(contract.unpause("Not paused".to_string()), Err(VoteError::NotPaused));
+        contract.pause("Token exploit".to_string()).unwrap();
+        assert_eq!(contract.pause("Again".to_string()), Err(VoteError::Paused));
+
+        assert_eq!(contract.add_proposal(new_proposal(0, 100, None)), Err(VoteError::Paused));
+        assert_eq!(contract.vote(id, true), Err(VoteError::Paused));
+        let no = Choice::Standard(VoteOption::No);
+        assert_eq!(contract.change_vote(id, no), Err(VoteError::Paused));
+        assert_eq!(contract.retract_vote(id), Err(VoteError::Paused));
+        assert_eq!(contract.execute(id), Err(VoteError::Paused));
+
+        // Queries and closing keep working
+        assert_eq!(contract.proposal_status(id), Some(ProposalStatus::Active));
+        end_voting(&mut contract, id);
+        assert!(contract.close_proposal(id).is_ok());
+
+        contract.unpause("Fixed".to_string()).unwrap();
+        assert!(!contract.is_paused());
+        assert!(contract.add_proposal(new_proposal(0, 100, None)).is_ok());
     }
 
     #[test]
-    fn test_proposal_count_empty() {
+    fn test_pause_requires_admin_or_pauser() {
         let admin_pk = mock_public_key(1);
         let token_id = ContractId::from_bytes([2u8; 32]);
-        let contract = create_test_contract(admin_pk, token_id);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let caller = Account::External(admin_pk);
 
-        assert_eq!(contract.proposal_count(), 0);
+        // The admin can pause without holding the role
+        contract.revoke_role(Role::Pauser, caller).unwrap();
+        contract.pause("Admin".to_string()).unwrap();
+
+        // A Pauser can pause without being the admin
+        contract.admin = Account::Contract(ContractId::from_bytes([9u8; 32]));
+        assert_eq!(contract.unpause("Anyone".to_string()), Err(VoteError::MissingRole));
+        contract.roles.entry(Role::Pauser).or_default().insert(caller);
+        assert_eq!(contract.unpause("a".repeat(MAX_REASON_LEN + 1)), Err(VoteError::ReasonTooLong));
+        contract.unpause("Pauser".to_string()).unwrap();
     }
 
+    // ==================== Migration Tests ====================
+
     #[test]
-    fn test_admin_getter() {
+    fn test_freeze_makes_contract_read_only() {
         let admin_pk = mock_public_key(1);
         let token_id = ContractId::from_bytes([2u8; 32]);
-        let contract = create_test_contract(admin_pk, token_id);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let successor = ContractId::from_bytes([7u8; 32]);
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
 
-        assert_eq!(contract.admin(), Account::External(admin_pk));
+        assert_eq!(contract.freeze(self_id()), Err(VoteError::NotSuccessor));
+        contract.proposals.get_mut(&id).unwrap().deposit = 10;
+        assert_eq!(contract.freeze(successor), Err(VoteError::UnsettledDeposits));
+        contract.proposals.get_mut(&id).unwrap().deposit = 0;
+        contract.freeze(successor).unwrap();
+        assert_eq!(contract.successor(), Some(successor));
+
+        assert_eq!(contract.add_proposal(new_proposal(0, 100, None)), Err(VoteError::Frozen));
+        assert_eq!(contract.vote(id, true), Err(VoteError::Frozen));
+        assert_eq!(contract.delegate(Account::default()), Err(VoteError::Frozen));
+        assert_eq!(contract.set_timelock(0, 0), Err(VoteError::Frozen));
+        assert_eq!(contract.pause("Frozen".to_string()), Err(VoteError::Frozen));
+        assert_eq!(contract.freeze(successor), Err(VoteError::Frozen));
+        assert_eq!(contract.archive_proposals(10), 0);
+
+        // Queries keep working, but only the successor can export
+        assert_eq!(contract.proposal_status(id), Some(ProposalStatus::Active));
+        assert_eq!(contract.export_state().err(), Some(VoteError::NotSuccessor));
     }
 
     #[test]
-    fn test_token_contract_getter() {
+    fn test_import_snapshot() {
         let admin_pk = mock_public_key(1);
         let token_id = ContractId::from_bytes([2u8; 32]);
-        let contract = create_test_contract(admin_pk, token_id);
+        let mut old = create_test_contract(admin_pk, token_id);
+        let id = old.add_proposal(new_proposal(0, 100, None)).unwrap();
+        seed_ballot(&mut old, id, VoteOption::Yes, 100);
+        old.delegate(Account::Contract(ContractId::from_bytes([5u8; 32]))).unwrap();
+        old.set_archive_age(42).unwrap();
+        old.allow_contract_voter(ContractId::from_bytes([6u8; 32])).unwrap();
+        old.pause("Migrating".to_string()).unwrap();
+        let committee = Committee { members: vec![admin_pk], threshold: 1 };
+        old.set_committee(committee.clone()).unwrap();
+        // Mock keys are all alike, so a waiting operation is added directly
+        let operation = AdminOperation::SetArchiveAge(7);
+        let pending =
+            PendingOperation { id: 0, operation, submitter: admin_pk, approvals: vec![admin_pk] };
+        old.operations.insert(0, pending);
+        old.next_operation_id = 1;
+        let snapshot = old.snapshot(ContractId::from_bytes([7u8; 32]));
 
-        assert_eq!(contract.token_contract(), token_id);
+        let mut new = create_test_contract(admin_pk, token_id);
+        assert_eq!(new.migrate_from(self_id()), Err(VoteError::MigrationFailed));
+        assert_eq!(
+            new.import_snapshot(StateSnapshot { version: STATE_VERSION + 1, ..snapshot.clone() }),
+            Err(VoteError::UnsupportedStateVersion)
+        );
+        assert_eq!(
+            new.import_snapshot(StateSnapshot { version: STATE_VERSION - 2, ..snapshot.clone() }),
+            Err(VoteError::OutdatedStateVersion)
+        );
+        new.import_snapshot(snapshot).unwrap();
+
+        let voter = sender_account().unwrap();
+        assert_eq!(new.proposal_count(), 1);
+        assert_eq!(new.get_ballot(voter, id), old.get_ballot(voter, id));
+        let delegate = Account::Contract(ContractId::from_bytes([5u8; 32]));
+        assert_eq!(new.delegators_of(delegate, DelegationScope::Global), vec![voter]);
+        assert_eq!(new.archive_age(), 42);
+        assert_eq!(new.contract_voters(), vec![ContractId::from_bytes([6u8; 32])]);
+        assert_eq!(new.predecessor(), Some(self_id()));
+        assert_eq!(new.live_proposals, 1);
+
+        // The pause carries over, but operations approved against the old contract do not
+        assert!(new.is_paused());
+        assert_eq!(new.committee(), Some(committee));
+        assert!(new.pending_operations().is_empty());
+        assert_eq!(
+            new.submit_operation(AdminOperation::MigrateFrom(self_id())),
+            Err(VoteError::StateNotEmpty)
+        );
     }
 
     #[test]
-    fn test_pending_admin_initially_none() {
+    fn test_import_previous_version_snapshot() {
         let admin_pk = mock_public_key(1);
         let token_id = ContractId::from_bytes([2u8; 32]);
-        let contract = create_test_contract(admin_pk, token_id);
+        let mut old = create_test_contract(admin_pk, token_id);
+        let id = old.add_proposal(new_proposal(0, 100, None)).unwrap();
+        seed_ballot(&mut old, id, VoteOption::Yes, 100);
+        let current = old.snapshot(ContractId::from_bytes([7u8; 32]));
 
-        assert!(contract.pending_admin().is_none());
+        // The same state as exported by a contract at version 4
+        let snapshot = StateSnapshotV4 {
+            version: 4,
+            source: current.source,
+            successor: current.successor,
+            admin: current.admin,
+            token_contract: current.token_contract,
+            voting_rules: current.voting_rules,
+            voting_power_strategy: current.voting_power_strategy,
+            proposals: current.proposals,
+            votes: current.votes,
+            delegates: current.delegates,
+            delegated_votes: current.delegated_votes,
+            next_proposal_id: current.next_proposal_id,
+            archived: current.archived,
+            archive_age: current.archive_age,
+            execution_delay: current.execution_delay,
+            grace_period: current.grace_period,
+            roles: current.roles,
+            submission_policy: current.submission_policy,
+            contract_voters: current.contract_voters,
+            committee: current.committee,
+            paused: current.paused,
+            rounds: current.rounds,
+            next_round_id: current.next_round_id,
+            credits_spent: current.credits_spent,
+        };
+
+        let mut new = create_test_contract(admin_pk, token_id);
+        new.import_snapshot(snapshot.into()).unwrap();
+        let voter = sender_account().unwrap();
+        assert_eq!(new.proposal_status(id), Some(ProposalStatus::Active));
+        assert_eq!(new.get_ballot(voter, id), old.get_ballot(voter, id));
+        assert!(new.get_election(0).is_none());
+        assert_eq!(new.state_version(), STATE_VERSION);
     }
 
-    // ==================== Has Account Voted Tests ====================
+    // ==================== Submission Policy Tests ====================
 
     #[test]
-    fn test_has_account_voted_false_no_proposal() {
+    fn test_permissionless_proposals_need_threshold() {
         let admin_pk = mock_public_key(1);
-        let voter_pk = mock_public_key(2);
         let token_id = ContractId::from_bytes([2u8; 32]);
-        let contract = create_test_contract(admin_pk, token_id);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        contract.revoke_role(Role::Proposer, Account::External(admin_pk)).unwrap();
 
-        // No proposal exists, should return false
-        assert!(!contract.has_account_voted(voter_pk, 0));
+        assert_eq!(contract.add_proposal(new_proposal(0, 100, None)), Err(VoteError::MissingRole));
+
+        // Token balances are 0 outside the VM, so any threshold above 0 is out of reach
+        contract.submission_policy =
+            SubmissionPolicy { permissionless: true, threshold: 1, ..SubmissionPolicy::ADMIN_ONLY };
+        assert_eq!(
+            contract.add_proposal(new_proposal(0, 100, None)),
+            Err(VoteError::BelowProposalThreshold)
+        );
+
+        // The balance must be above the threshold, so an empty one never meets even 0
+        contract.submission_policy.threshold = 0;
+        assert_eq!(
+            contract.add_proposal(new_proposal(0, 100, None)),
+            Err(VoteError::BelowProposalThreshold)
+        );
+
+        // Only Proposers may override the global rules or strategy, or attach actions
+        let base = new_proposal(0, 100, None);
+        let overrides = [
+            NewProposal { rules: Some(VotingRules::DEFAULT), ..base.clone() },
+            NewProposal { strategy: Some(VotingPowerStrategy::DEFAULT), ..base.clone() },
+            NewProposal { actions: vec![action("transfer")], ..base },
+        ];
+        for params in overrides {
+            assert_eq!(contract.add_proposal(params.clone()), Err(VoteError::ProposerOnly));
+            contract.grant_role(Role::Proposer, Account::External(admin_pk)).unwrap();
+            let id = contract.add_proposal(params).unwrap();
+            assert_eq!(contract.proposals[&id].creator, Account::External(admin_pk));
+            contract.revoke_role(Role::Proposer, Account::External(admin_pk)).unwrap();
+        }
     }
 
     #[test]
-    fn test_get_account_vote_weight_zero_no_vote() {
+    fn test_proposal_deposits() {
         let admin_pk = mock_public_key(1);
-        let voter_pk = mock_public_key(2);
         let token_id = ContractId::from_bytes([2u8; 32]);
-        let contract = create_test_contract(admin_pk, token_id);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let with_deposit = |deposit| NewProposal { deposit, ..new_proposal(0, 100, None) };
 
-        // No vote cast, should return 0
-        assert_eq!(contract.get_account_vote_weight(voter_pk, 0), 0);
+        // Deposits need a treasury to be slashed to
+        assert_eq!(contract.add_proposal(with_deposit(10)), Err(VoteError::DepositNotAccepted));
+        let no_treasury = SubmissionPolicy { min_deposit: 10, ..SubmissionPolicy::ADMIN_ONLY };
+        assert_eq!(
+            contract.set_submission_policy(no_treasury),
+            Err(VoteError::DepositNotAccepted)
+        );
+        let policy = SubmissionPolicy {
+            permissionless: true,
+            threshold: 0,
+            min_deposit: 10,
+            treasury: Some(mock_public_key(2)),
+        };
+        contract.set_submission_policy(policy).unwrap();
+        assert_eq!(contract.submission_policy(), policy);
+
+        // Proposers may lock a deposit but are not required to
+        let id = contract.add_proposal(with_deposit(0)).unwrap();
+        assert_eq!(contract.proposals[&id].deposit, 0);
+
+        // The deposit is checked before the balance, which is empty outside the VM
+        contract.revoke_role(Role::Proposer, Account::External(admin_pk)).unwrap();
+        assert_eq!(contract.add_proposal(with_deposit(5)), Err(VoteError::DepositTooLow));
+        assert_eq!(
+            contract.add_proposal(with_deposit(10)),
+            Err(VoteError::BelowProposalThreshold)
+        );
     }
 
-    // ==================== Constants Tests ====================
+    // ==================== Archiving Tests ====================
 
     #[test]
-    fn test_max_proposal_description_length() {
-        assert_eq!(MAX_PROPOSAL_DESC_LEN, 256);
+    fn test_archive_frees_votes_and_keeps_result() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        seed_ballot(&mut contract, id, VoteOption::Yes, 1000);
+        end_voting(&mut contract, id);
+        let outcome = contract.close_proposal(id).unwrap();
+
+        // Too recent to archive under the default age
+        assert_eq!(contract.archive_proposals(10), 0);
+
+        contract.set_archive_age(0).unwrap();
+        assert_eq!(contract.archive_proposals(10), 1);
+        assert!(contract.proposals.is_empty());
+        assert!(!contract.votes.contains_key(&id));
+
+        let record = contract.get_archived_proposal(id).unwrap();
+        assert_eq!(record.voter_count, 1);
+        assert_eq!(record.proposal.outcome, Some(outcome));
+        let proposal = contract.get_proposal(id).unwrap();
+        assert_eq!(proposal.yes_votes, 1000);
+        assert_eq!(contract.proposal_status(id), Some(proposal.status));
+        assert_eq!(contract.archived_count(), 1);
     }
 
     #[test]
-    fn test_max_proposals() {
-        assert_eq!(MAX_PROPOSALS, 100);
+    fn test_archive_skips_unfinalized_proposals() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        let id = contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        end_voting(&mut contract, id);
+        contract.set_archive_age(0).unwrap();
+
+        // Voting has ended but the outcome was never recorded
+        assert_eq!(contract.archive_proposals(10), 0);
+        assert!(contract.get_archived_proposal(id).is_none());
+    }
+
+    #[test]
+    fn test_proposal_cap_counts_only_live_proposals() {
+        let admin_pk = mock_public_key(1);
+        let token_id = ContractId::from_bytes([2u8; 32]);
+        let mut contract = create_test_contract(admin_pk, token_id);
+        for _ in 0..MAX_PROPOSALS {
+            contract.add_proposal(new_proposal(0, 100, None)).unwrap();
+        }
+        assert_eq!(
+            contract.add_proposal(new_proposal(0, 100, None)),
+            Err(VoteError::MaxProposalsReached)
+        );
+
+        contract.cancel_proposal(0).unwrap();
+        assert_eq!(contract.live_proposals as usize, MAX_PROPOSALS - 1);
+        assert!(contract.add_proposal(new_proposal(0, 100, None)).is_ok());
     }
 
-    // ==================== Error Message Tests ====================
+    // ==================== Error Tests ====================
+
+    #[test]
+    fn test_error_codes_are_stable() {
+        assert_eq!(VoteError::ShieldedNotSupported.code(), 1);
+        assert_eq!(VoteError::NotAdmin.code(), 2);
+        assert_eq!(VoteError::ProposalNotFound.code(), 8);
+        assert_eq!(VoteError::AlreadyVoted.code(), 11);
+        assert_eq!(VoteError::EmptyCategory.code(), 32);
+    }
 
     #[test]
     fn test_error_messages_defined() {
-        assert!(!error::NOT_ADMIN.is_empty());
-        assert!(!error::PROPOSAL_NOT_FOUND.is_empty());
-        assert!(!error::ALREADY_VOTED.is_empty());
-        assert!(!error::NO_VOTING_POWER.is_empty());
-        assert!(!error::PROPOSAL_NOT_ACTIVE.is_empty());
-        assert!(!error::CONTRACTS_CANNOT_VOTE.is_empty());
+        assert_eq!(VoteError::NotAdmin.message(), "Caller is not admin");
+        assert!(!VoteError::ProposalNotFound.message().is_empty());
+        assert!(!VoteError::AlreadyVoted.message().is_empty());
+        assert!(!VoteError::NoVotingPower.message().is_empty());
+        assert!(!VoteError::ProposalCancelled.message().is_empty());
+        assert!(!VoteError::VotingNotEnded.message().is_empty());
+        assert!(!VoteError::ContractsCannotVote.message().is_empty());
     }
 }
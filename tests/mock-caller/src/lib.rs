@@ -0,0 +1,59 @@
+//! Mock Caller Contract for Testing
+//!
+//! Forwards calls to another contract, so tests can reach the vote contract
+//! with a contract as the sender instead of a public key. It also answers
+//! `total_supply_at` like a token, making a stored call back into a contract
+//! first, which lets tests call in while that contract is in the middle of a call.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use dusk_core::abi::{self, ContractId};
+
+/// Mock caller state
+struct MockCaller {
+    /// Call made whenever the supply is queried (target, function, encoded argument)
+    callback: Option<(ContractId, String, Vec<u8>)>,
+}
+
+static mut STATE: MockCaller = MockCaller { callback: None };
+
+impl MockCaller {
+    /// Store the call to make when the supply is queried
+    fn set_callback(&mut self, callback: (ContractId, String, Vec<u8>)) {
+        self.callback = Some(callback);
+    }
+
+    /// Make the stored call, then report no supply
+    fn total_supply_at(&self, _height: u64) -> u64 {
+        if let Some((target, function, args)) = &self.callback {
+            // Tests read the outcome from the target's state
+            let _ = abi::call_raw(*target, function, args);
+        }
+        0
+    }
+}
+
+/// Call `function` on `target` with the encoded argument, returning the encoded result
+#[no_mangle]
+unsafe fn call(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(target, function, args): (ContractId, String, Vec<u8>)| {
+        abi::call_raw(target, &function, &args).expect("Forwarded call should succeed")
+    })
+}
+
+/// Store the call to make back into a contract when the supply is queried
+#[no_mangle]
+unsafe fn set_callback(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |callback: (ContractId, String, Vec<u8>)| STATE.set_callback(callback))
+}
+
+/// Make the stored call, then report no supply
+#[no_mangle]
+unsafe fn total_supply_at(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |height: u64| STATE.total_supply_at(height))
+}
@@ -0,0 +1,93 @@
+//! Mock Registry Contract for Testing
+//!
+//! A set of registered accounts answering `is_registered`. Used to test the
+//! vote contract's one-account-one-vote strategy.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+use dusk_core::abi::{self, ContractId};
+use dusk_core::signatures::bls::PublicKey;
+
+use rkyv::{Archive, Deserialize, Serialize};
+use bytecheck::CheckBytes;
+
+/// Registered account - same layout as the vote contract's `Account`
+#[derive(Clone, Copy, Archive, Serialize, Deserialize)]
+#[archive_attr(derive(CheckBytes))]
+pub enum Account {
+    External(PublicKey),
+    Contract(ContractId),
+}
+
+impl Account {
+    /// Key the account is stored under, distinct for keys and contracts
+    fn key(&self) -> Vec<u8> {
+        match self {
+            Account::External(pk) => pk.to_raw_bytes().to_vec(),
+            Account::Contract(id) => id.to_bytes().to_vec(),
+        }
+    }
+}
+
+/// Mock registry state
+struct MockRegistry {
+    /// Keys of the registered accounts
+    registered: BTreeSet<Vec<u8>>,
+}
+
+static mut STATE: MockRegistry = MockRegistry {
+    registered: BTreeSet::new(),
+};
+
+impl MockRegistry {
+    /// Initialize with a list of registered accounts
+    fn init(&mut self, accounts: Vec<Account>) {
+        for account in accounts {
+            self.register(account);
+        }
+    }
+
+    /// Check whether an account is registered
+    fn is_registered(&self, account: &Account) -> bool {
+        self.registered.contains(&account.key())
+    }
+
+    /// Register an account (for testing)
+    fn register(&mut self, account: Account) {
+        self.registered.insert(account.key());
+    }
+
+    /// Remove an account from the registry (for testing)
+    fn deregister(&mut self, account: Account) {
+        self.registered.remove(&account.key());
+    }
+}
+
+/// Initialize the mock registry with registered accounts
+#[no_mangle]
+unsafe fn init(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |accounts: Vec<Account>| STATE.init(accounts))
+}
+
+/// Check whether an account is registered
+#[no_mangle]
+unsafe fn is_registered(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |account: Account| STATE.is_registered(&account))
+}
+
+/// Register an account (test helper)
+#[no_mangle]
+unsafe fn register(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |account: Account| STATE.register(account))
+}
+
+/// Remove an account from the registry (test helper)
+#[no_mangle]
+unsafe fn deregister(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |account: Account| STATE.deregister(account))
+}
@@ -0,0 +1,74 @@
+//! Mock Stake Contract for Testing
+//!
+//! Stands in for the Dusk stake contract, answering `get_stake` with a staked
+//! amount per public key. Used to test the vote contract's staked voting
+//! power strategy, so it must be deployed at `STAKE_CONTRACT`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use dusk_core::abi;
+use dusk_core::signatures::bls::PublicKey;
+use dusk_core::stake::{StakeAmount, StakeData};
+
+/// Mock stake state
+struct MockStake {
+    /// Staked value per public key
+    stakes: BTreeMap<[u8; 96], u64>,
+}
+
+static mut STATE: MockStake = MockStake {
+    stakes: BTreeMap::new(),
+};
+
+impl MockStake {
+    /// Initialize with a list of (public_key, staked_value) pairs
+    fn init(&mut self, initial_stakes: Vec<(PublicKey, u64)>) {
+        for (pk, value) in initial_stakes {
+            self.set_stake(pk, value);
+        }
+    }
+
+    /// Get the stake of a public key, shaped like the stake contract's answer
+    fn get_stake(&self, public_key: &PublicKey) -> Option<StakeData> {
+        self.stakes.get(&public_key.to_raw_bytes()).map(|value| StakeData {
+            amount: Some(StakeAmount { value: *value, ..StakeAmount::default() }),
+            ..StakeData::default()
+        })
+    }
+
+    /// Set the staked value of a public key, removing the stake at zero (for testing)
+    fn set_stake(&mut self, public_key: PublicKey, value: u64) {
+        if value == 0 {
+            self.stakes.remove(&public_key.to_raw_bytes());
+        } else {
+            self.stakes.insert(public_key.to_raw_bytes(), value);
+        }
+    }
+}
+
+/// Initialize the mock stake contract with staked values
+#[no_mangle]
+unsafe fn init(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |initial_stakes: Vec<(PublicKey, u64)>| {
+        STATE.init(initial_stakes)
+    })
+}
+
+/// Get the stake of a public key
+#[no_mangle]
+unsafe fn get_stake(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |public_key: PublicKey| STATE.get_stake(&public_key))
+}
+
+/// Set the staked value of a public key (test helper)
+#[no_mangle]
+unsafe fn set_stake(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(public_key, value): (PublicKey, u64)| {
+        STATE.set_stake(public_key, value)
+    })
+}
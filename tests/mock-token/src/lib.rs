@@ -2,6 +2,10 @@
 //!
 //! A simple token contract that stores balances and provides balance_of query.
 //! Used to test the vote contract's token balance integration.
+//!
+//! Every balance change is checkpointed at the current block height, so
+//! `balance_of_at` can answer historical queries for snapshot-based voting.
+//! Contracts can hold balances too, queried through `balance_of_account`.
 
 #![no_std]
 
@@ -10,40 +14,94 @@ extern crate alloc;
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 
-use dusk_core::abi;
+use dusk_core::abi::{self, ContractId};
 use dusk_core::signatures::bls::PublicKey;
 
 use rkyv::{Archive, Deserialize, Serialize};
 use bytecheck::CheckBytes;
 
+/// Token holder - same layout as the vote contract's `Account`
+#[derive(Clone, Copy, Archive, Serialize, Deserialize)]
+#[archive_attr(derive(CheckBytes))]
+pub enum Account {
+    External(PublicKey),
+    Contract(ContractId),
+}
+
+/// Balance checkpoints as (block_height, balance), sorted by height
+type Checkpoints = Vec<(u64, u64)>;
+
 /// Mock token state
 struct MockToken {
-    balances: BTreeMap<[u8; 96], u64>,
+    /// Balance checkpoints per public key
+    checkpoints: BTreeMap<[u8; 96], Checkpoints>,
+    /// Balance checkpoints per contract
+    contract_checkpoints: BTreeMap<[u8; 32], Checkpoints>,
 }
 
 static mut STATE: MockToken = MockToken {
-    balances: BTreeMap::new(),
+    checkpoints: BTreeMap::new(),
+    contract_checkpoints: BTreeMap::new(),
 };
 
 impl MockToken {
     /// Initialize with a list of (public_key, balance) pairs
+    /// Initial balances are checkpointed at genesis (height 0)
     fn init(&mut self, initial_balances: Vec<(PublicKey, u64)>) {
         for (pk, balance) in initial_balances {
-            self.balances.insert(pk.to_raw_bytes(), balance);
+            self.checkpoint(Account::External(pk), 0, balance);
         }
     }
 
-    /// Get balance for a public key
-    fn balance_of(&self, public_key: &PublicKey) -> u64 {
-        self.balances
-            .get(&public_key.to_raw_bytes())
-            .copied()
+    /// Get the balance checkpoints of an account
+    fn history(&self, account: &Account) -> Option<&Checkpoints> {
+        match account {
+            Account::External(pk) => self.checkpoints.get(&pk.to_raw_bytes()),
+            Account::Contract(id) => self.contract_checkpoints.get(&id.to_bytes()),
+        }
+    }
+
+    /// Get balance for an account
+    fn balance_of_account(&self, account: &Account) -> u64 {
+        self.history(account)
+            .and_then(|c| c.last())
+            .map(|(_, balance)| *balance)
             .unwrap_or(0)
     }
 
-    /// Set balance for a public key (for testing)
-    fn set_balance(&mut self, public_key: PublicKey, balance: u64) {
-        self.balances.insert(public_key.to_raw_bytes(), balance);
+    /// Get balance for an account as of the end of the given block
+    fn balance_of_account_at(&self, account: &Account, height: u64) -> u64 {
+        self.history(account)
+            .and_then(|c| c.iter().rev().find(|(h, _)| *h <= height))
+            .map(|(_, balance)| *balance)
+            .unwrap_or(0)
+    }
+
+    /// Get the total supply as of the end of the given block
+    fn total_supply_at(&self, height: u64) -> u64 {
+        self.checkpoints
+            .values()
+            .chain(self.contract_checkpoints.values())
+            .filter_map(|c| c.iter().rev().find(|(h, _)| *h <= height))
+            .map(|(_, balance)| *balance)
+            .sum()
+    }
+
+    /// Set balance for an account (for testing)
+    fn set_balance(&mut self, account: Account, balance: u64) {
+        self.checkpoint(account, abi::block_height(), balance);
+    }
+
+    /// Record a balance at a block height, overwriting a checkpoint at the same height
+    fn checkpoint(&mut self, account: Account, height: u64, balance: u64) {
+        let checkpoints = match account {
+            Account::External(pk) => self.checkpoints.entry(pk.to_raw_bytes()).or_default(),
+            Account::Contract(id) => self.contract_checkpoints.entry(id.to_bytes()).or_default(),
+        };
+        match checkpoints.last_mut() {
+            Some((h, b)) if *h == height => *b = balance,
+            _ => checkpoints.push((height, balance)),
+        }
     }
 }
 
@@ -59,14 +117,50 @@ unsafe fn init(arg_len: u32) -> u32 {
 #[no_mangle]
 unsafe fn balance_of(arg_len: u32) -> u32 {
     abi::wrap_call(arg_len, |public_key: PublicKey| {
-        STATE.balance_of(&public_key)
+        STATE.balance_of_account(&Account::External(public_key))
     })
 }
 
+/// Get balance for a public key as of the end of a block
+#[no_mangle]
+unsafe fn balance_of_at(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(public_key, height): (PublicKey, u64)| {
+        STATE.balance_of_account_at(&Account::External(public_key), height)
+    })
+}
+
+/// Get balance for an account (public key or contract)
+#[no_mangle]
+unsafe fn balance_of_account(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |account: Account| STATE.balance_of_account(&account))
+}
+
+/// Get balance for an account as of the end of a block
+#[no_mangle]
+unsafe fn balance_of_account_at(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(account, height): (Account, u64)| {
+        STATE.balance_of_account_at(&account, height)
+    })
+}
+
+/// Get the total supply as of the end of a block
+#[no_mangle]
+unsafe fn total_supply_at(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |height: u64| STATE.total_supply_at(height))
+}
+
 /// Set balance for a public key (test helper)
 #[no_mangle]
 unsafe fn set_balance(arg_len: u32) -> u32 {
     abi::wrap_call(arg_len, |(public_key, balance): (PublicKey, u64)| {
-        STATE.set_balance(public_key, balance)
+        STATE.set_balance(Account::External(public_key), balance)
+    })
+}
+
+/// Set balance for an account (test helper)
+#[no_mangle]
+unsafe fn set_account_balance(arg_len: u32) -> u32 {
+    abi::wrap_call(arg_len, |(account, balance): (Account, u64)| {
+        STATE.set_balance(account, balance)
     })
 }
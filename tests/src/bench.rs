@@ -0,0 +1,127 @@
+//! Gas benchmarks for the Vote Contract
+//!
+//! These are ignored by default since they deploy hundreds of proposals and
+//! thousands of voters. Run them with `make bench`, which sets `BENCH_REPORT`
+//! so the measured gas is printed, and compare the figures between revisions.
+
+use dusk_core::signatures::bls::{
+    PublicKey as AccountPublicKey, SecretKey as AccountSecretKey,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::{Proposal, TestSession, GAS_LIMIT, VOTE_CONTRACT_ID};
+
+/// Proposals created before measuring
+const PROPOSALS: u32 = 300;
+/// Accounts voting on the measured proposal
+const VOTERS: usize = 2_000;
+
+/// Print a measurement when `BENCH_REPORT` is set
+fn report(measurement: &str, gas: u64) {
+    if std::env::var_os("BENCH_REPORT").is_some() {
+        println!("bench: {measurement}: {gas} gas");
+    }
+}
+
+/// Deterministic voter keys, each funded with `balance` tokens
+fn funded_voters(session: &mut TestSession, count: usize, balance: u64) -> Vec<AccountPublicKey> {
+    let mut rng = StdRng::seed_from_u64(0xBE7C4);
+    (0..count)
+        .map(|_| {
+            let pk = AccountPublicKey::from(&AccountSecretKey::random(&mut rng));
+            session.set_token_balance(&pk, balance);
+            pk
+        })
+        .collect()
+}
+
+/// Create `count` proposals, cancelling all but the last so the live cap is
+/// never reached, and return the ID of the last one
+fn create_proposals(session: &mut TestSession, count: u32) -> u32 {
+    let admin = *TestSession::PK_ADMIN;
+    let mut id = 0;
+    for i in 0..count {
+        id = session
+            .add_proposal(&admin, "Benchmark proposal", 0, 1_000, None)
+            .expect("Adding proposal should succeed")
+            .data;
+        if i + 1 < count {
+            session.cancel_proposal(&admin, id).expect("Cancelling proposal should succeed");
+        }
+    }
+    id
+}
+
+#[test]
+#[ignore]
+fn bench_proposal_lookup() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+
+    let first = create_proposals(&mut session, 1);
+    let first_gas = session
+        .session
+        .call::<u32, Option<Proposal>>(VOTE_CONTRACT_ID, "get_proposal", &first, GAS_LIMIT)
+        .expect("Querying proposal should succeed")
+        .gas_spent;
+
+    let last = create_proposals(&mut session, PROPOSALS);
+    let last_gas = session
+        .session
+        .call::<u32, Option<Proposal>>(VOTE_CONTRACT_ID, "get_proposal", &last, GAS_LIMIT)
+        .expect("Querying proposal should succeed")
+        .gas_spent;
+    let vote_gas = session.vote(&admin, last, true).expect("Voting should succeed").gas_spent;
+
+    report("get_proposal with 1 proposal", first_gas);
+    report(&format!("get_proposal with {} proposals", PROPOSALS + 1), last_gas);
+    report(&format!("vote with {} proposals", PROPOSALS + 1), vote_gas);
+}
+
+#[test]
+#[ignore]
+fn bench_add_proposal() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+
+    let first_gas = session
+        .add_proposal(&admin, "Benchmark proposal", 0, 1_000, None)
+        .expect("Adding proposal should succeed")
+        .gas_spent;
+    create_proposals(&mut session, PROPOSALS);
+    let last_gas = session
+        .add_proposal(&admin, "Benchmark proposal", 0, 1_000, None)
+        .expect("Adding proposal should succeed")
+        .gas_spent;
+
+    report("add_proposal with no proposals", first_gas);
+    report(&format!("add_proposal with {} proposals", PROPOSALS + 1), last_gas);
+}
+
+#[test]
+#[ignore]
+fn bench_vote_with_many_voters() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+
+    let voters = funded_voters(&mut session, VOTERS, 100);
+    let id = create_proposals(&mut session, PROPOSALS);
+
+    let mut gas = Vec::with_capacity(voters.len());
+    for (i, voter) in voters.iter().enumerate() {
+        let receipt = session.vote(voter, id, i % 2 == 0).expect("Voting should succeed");
+        gas.push(receipt.gas_spent);
+    }
+    session.set_block_height(1_000);
+    let close_gas = session
+        .close_proposal(&admin, id)
+        .expect("Closing proposal should succeed")
+        .gas_spent;
+
+    let total: u64 = gas.iter().sum();
+    report("first vote", gas[0]);
+    report(&format!("vote #{VOTERS}"), gas[VOTERS - 1]);
+    report(&format!("average vote over {VOTERS} voters"), total / VOTERS as u64);
+    report(&format!("close_proposal after {VOTERS} votes"), close_gas);
+}
@@ -1,22 +1,46 @@
 //! Test-suite for testing the Vote smart contract
 //!
 //! Uses piecrust VM for direct contract testing.
-//! Note: Functions requiring sender authentication need the full
-//! network session with transactions (see EMT test framework).
+//! Functions requiring sender authentication are called by setting the
+//! public sender and block height metadata on the session, mimicking what
+//! the network does for a moonlight transaction.
 
 use std::sync::LazyLock;
 
-use dusk_core::abi::ContractId;
+use dusk_core::abi::{ContractId, Metadata};
 use dusk_core::signatures::bls::{
     PublicKey as AccountPublicKey, SecretKey as AccountSecretKey,
 };
-use piecrust::{ContractData, Session, SessionData, VM};
+use dusk_core::stake::STAKE_CONTRACT;
+use piecrust::{CallReceipt, ContractData, Error, Session, SessionData, VM};
+
+use bytecheck::CheckBytes;
+use rkyv::de::deserializers::SharedDeserializeMap;
+use rkyv::ser::serializers::AllocSerializer;
+use rkyv::validation::validators::DefaultValidator;
+use rkyv::{Archive, Deserialize, Serialize};
 
 use rand::rngs::StdRng;
 use rand::SeedableRng;
 
 // Re-export types from the vote contract
-pub use vote_contract::{Account, Proposal};
+pub use vote_contract::{
+    Account, Action, ActionResult, AdminOperation, Ballot, Choice, Committee, DelegationScope,
+    Election, NewElection, NewProposal, NewRound, PendingOperation, PowerSource, PowerTransform,
+    Proposal, ProposalFilter, ProposalOutcome, ProposalRecord, ProposalStatus, Quorum, RankedBallot,
+    Role, Round, StateSnapshot, SubmissionPolicy, Threshold, TokenWeight, VoteError, VoteOption,
+    VotingPowerStrategy, VotingRules,
+};
+
+// Re-export event types from the vote contract
+pub use vote_contract::{
+    AdminAccepted, AdminProposed, AdminTransferCancelled, ArchiveAgeUpdated, CommitteeUpdated,
+    ContractFrozen, ContractVoterChanged, DelegationChanged, DepositSettled, ElectionCreated,
+    ElectionFinalized, OperationApproved, OperationClosed, PauseChanged, ProposalArchived,
+    ProposalCancelled, ProposalClosed, ProposalCreated, ProposalExecuted, ProposalQueued,
+    RankedBallotCast, RoleChanged, RoundCreated, StateMigrated, SubmissionPolicyUpdated,
+    TimelockUpdated, VoteCast, VotingPowerStrategyUpdated, VotingRulesUpdated,
+};
 
 // Contract bytecode - include at compile time
 const VOTE_BYTECODE: &[u8] = include_bytes!(
@@ -25,13 +49,54 @@ const VOTE_BYTECODE: &[u8] = include_bytes!(
 const TOKEN_BYTECODE: &[u8] = include_bytes!(
     "../../target/wasm64-unknown-unknown/release/mock_token.wasm"
 );
+const CALLER_BYTECODE: &[u8] = include_bytes!(
+    "../../target/wasm64-unknown-unknown/release/mock_caller.wasm"
+);
+const STAKE_BYTECODE: &[u8] = include_bytes!(
+    "../../target/wasm64-unknown-unknown/release/mock_stake.wasm"
+);
+const REGISTRY_BYTECODE: &[u8] = include_bytes!(
+    "../../target/wasm64-unknown-unknown/release/mock_registry.wasm"
+);
 
 pub const VOTE_CONTRACT_ID: ContractId = ContractId::from_bytes([1; 32]);
 pub const TOKEN_CONTRACT_ID: ContractId = ContractId::from_bytes([2; 32]);
+/// Where `deploy_successor` puts a fresh vote contract to migrate into
+pub const SUCCESSOR_CONTRACT_ID: ContractId = ContractId::from_bytes([3; 32]);
+pub const REGISTRY_CONTRACT_ID: ContractId = ContractId::from_bytes([4; 32]);
+/// A second mock token, for multi-token voting power
+pub const BONUS_TOKEN_CONTRACT_ID: ContractId = ContractId::from_bytes([5; 32]);
+/// Where `deploy_caller` puts a contract that forwards calls to the vote contract
+/// and can call back into it when asked for its supply
+pub const CALLER_CONTRACT_ID: ContractId = ContractId::from_bytes([6; 32]);
 
 const DEPLOYER: [u8; 64] = [0u8; 64];
 const GAS_LIMIT: u64 = 0x10_000_000;
 
+/// Unwrap the VM result of a fallible vote contract call
+/// Panics if the call trapped, so tests can assert on the exact `VoteError`
+fn vote_result<T>(
+    receipt: Result<CallReceipt<Result<T, VoteError>>, Error>,
+) -> Result<CallReceipt<T>, VoteError> {
+    let CallReceipt { gas_limit, gas_spent, events, call_tree, data } =
+        receipt.expect("Vote contract call should not trap");
+    data.map(|data| CallReceipt { gas_limit, gas_spent, events, call_tree, data })
+}
+
+/// Decode the events the vote contract emitted under `topic` during a call
+pub fn events<T, E>(receipt: &CallReceipt<T>, topic: &str) -> Vec<E>
+where
+    E: Archive,
+    for<'a> E::Archived: CheckBytes<DefaultValidator<'a>> + Deserialize<E, SharedDeserializeMap>,
+{
+    receipt
+        .events
+        .iter()
+        .filter(|event| event.source == VOTE_CONTRACT_ID && event.topic == topic)
+        .map(|event| rkyv::from_bytes(&event.data).expect("Event data should deserialize"))
+        .collect()
+}
+
 /// Test session for vote contract testing
 pub struct TestSession {
     session: Session,
@@ -96,6 +161,44 @@ impl TestSession {
             )
             .expect("Deploying token contract should succeed");
 
+        // Deploy the second token, where only voter 2 holds a balance
+        session
+            .deploy(
+                TOKEN_BYTECODE,
+                ContractData::builder()
+                    .owner(DEPLOYER)
+                    .init_arg(&vec![(*Self::PK_VOTER2, 50u64)])
+                    .contract_id(BONUS_TOKEN_CONTRACT_ID),
+                GAS_LIMIT,
+            )
+            .expect("Deploying bonus token contract should succeed");
+
+        // Deploy the mock stake contract where the real one lives, with voter 1 staking
+        session
+            .deploy(
+                STAKE_BYTECODE,
+                ContractData::builder()
+                    .owner(DEPLOYER)
+                    .init_arg(&vec![(*Self::PK_VOTER1, 400u64)])
+                    .contract_id(STAKE_CONTRACT),
+                GAS_LIMIT,
+            )
+            .expect("Deploying stake contract should succeed");
+
+        // Deploy the mock registry with the admin and voter 1 registered
+        let registered =
+            vec![Account::External(*Self::PK_ADMIN), Account::External(*Self::PK_VOTER1)];
+        session
+            .deploy(
+                REGISTRY_BYTECODE,
+                ContractData::builder()
+                    .owner(DEPLOYER)
+                    .init_arg(&registered)
+                    .contract_id(REGISTRY_CONTRACT_ID),
+                GAS_LIMIT,
+            )
+            .expect("Deploying registry contract should succeed");
+
         // Deploy vote contract with admin and token contract
         let admin = Account::External(*Self::PK_ADMIN);
         session
@@ -109,7 +212,756 @@ impl TestSession {
             )
             .expect("Deploying vote contract should succeed");
 
-        Self { session }
+        let mut test_session = Self { session };
+        test_session.set_block_height(0);
+        test_session
+    }
+
+    /// Deploy an empty vote contract at `SUCCESSOR_CONTRACT_ID`, also administered by `PK_ADMIN`
+    pub fn deploy_successor(&mut self) {
+        let admin = Account::External(*Self::PK_ADMIN);
+        self.session
+            .deploy(
+                VOTE_BYTECODE,
+                ContractData::builder()
+                    .owner(DEPLOYER)
+                    .init_arg(&(admin, TOKEN_CONTRACT_ID))
+                    .contract_id(SUCCESSOR_CONTRACT_ID),
+                GAS_LIMIT,
+            )
+            .expect("Deploying successor contract should succeed");
+    }
+
+    /// Deploy the caller mock at `CALLER_CONTRACT_ID`
+    pub fn deploy_caller(&mut self) {
+        self.session
+            .deploy(
+                CALLER_BYTECODE,
+                ContractData::builder().owner(DEPLOYER).contract_id(CALLER_CONTRACT_ID),
+                GAS_LIMIT,
+            )
+            .expect("Deploying caller contract should succeed");
+    }
+
+    /// Set the block height seen by the contracts
+    pub fn set_block_height(&mut self, height: u64) {
+        self.session.set_meta(Metadata::BLOCK_HEIGHT, height);
+    }
+
+    /// Set the moonlight sender seen by the contracts for subsequent calls
+    fn set_sender(&mut self, pk: &AccountPublicKey) {
+        self.session.set_meta(Metadata::PUBLIC_SENDER, Some(*pk));
+    }
+
+    // Helper methods for state-changing functions (called as `sender`)
+
+    pub fn add_proposal(
+        &mut self,
+        sender: &AccountPublicKey,
+        description: &str,
+        start_height: u64,
+        end_height: u64,
+        rules: Option<VotingRules>,
+    ) -> Result<CallReceipt<u32>, VoteError> {
+        self.add_proposal_with(
+            sender,
+            NewProposal {
+                description: description.to_string(),
+                start_height,
+                end_height,
+                rules,
+                strategy: None,
+                options: Vec::new(),
+                category: String::new(),
+                actions: Vec::new(),
+                deposit: 0,
+                round: None,
+            },
+        )
+    }
+
+    pub fn add_proposal_with(
+        &mut self,
+        sender: &AccountPublicKey,
+        params: NewProposal,
+    ) -> Result<CallReceipt<u32>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<NewProposal, Result<u32, VoteError>>(
+            VOTE_CONTRACT_ID,
+            "add_proposal",
+            &params,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn close_proposal(
+        &mut self,
+        sender: &AccountPublicKey,
+        id: u32,
+    ) -> Result<CallReceipt<ProposalOutcome>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<u32, Result<ProposalOutcome, VoteError>>(
+            VOTE_CONTRACT_ID,
+            "close_proposal",
+            &id,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn cancel_proposal(
+        &mut self,
+        sender: &AccountPublicKey,
+        id: u32,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<u32, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "cancel_proposal",
+            &id,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn vote(
+        &mut self,
+        sender: &AccountPublicKey,
+        id: u32,
+        vote_yes: bool,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<(u32, bool), Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "vote",
+            &(id, vote_yes),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn cast_vote(
+        &mut self,
+        sender: &AccountPublicKey,
+        id: u32,
+        option: VoteOption,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<(u32, VoteOption), Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "cast_vote",
+            &(id, option),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn vote_choice(
+        &mut self,
+        sender: &AccountPublicKey,
+        id: u32,
+        option_index: u32,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<(u32, u32), Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "vote_choice",
+            &(id, option_index),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn change_vote(
+        &mut self,
+        sender: &AccountPublicKey,
+        id: u32,
+        choice: Choice,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<(u32, Choice), Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "change_vote",
+            &(id, choice),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn retract_vote(
+        &mut self,
+        sender: &AccountPublicKey,
+        id: u32,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<u32, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "retract_vote",
+            &id,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn create_round(
+        &mut self,
+        sender: &AccountPublicKey,
+        start_height: u64,
+        end_height: u64,
+    ) -> Result<CallReceipt<u32>, VoteError> {
+        self.set_sender(sender);
+        let params = NewRound { description: "Round".to_string(), start_height, end_height };
+        let receipt = self.session.call::<NewRound, Result<u32, VoteError>>(
+            VOTE_CONTRACT_ID,
+            "create_round",
+            &params,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn vote_quadratic(
+        &mut self,
+        sender: &AccountPublicKey,
+        id: u32,
+        support: bool,
+        votes: u64,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<(u32, bool, u64), Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "vote_quadratic",
+            &(id, support, votes),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn create_election(
+        &mut self,
+        sender: &AccountPublicKey,
+        params: NewElection,
+    ) -> Result<CallReceipt<u32>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<NewElection, Result<u32, VoteError>>(
+            VOTE_CONTRACT_ID,
+            "create_election",
+            &params,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn vote_ranked(
+        &mut self,
+        sender: &AccountPublicKey,
+        id: u32,
+        ranking: &[u8],
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<(u32, Vec<u8>), Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "vote_ranked",
+            &(id, ranking.to_vec()),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn finalize_election(
+        &mut self,
+        sender: &AccountPublicKey,
+        id: u32,
+    ) -> Result<CallReceipt<Vec<Account>>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<u32, Result<Vec<Account>, VoteError>>(
+            VOTE_CONTRACT_ID,
+            "finalize_election",
+            &id,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn delegate(
+        &mut self,
+        sender: &AccountPublicKey,
+        to: &AccountPublicKey,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<Account, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "delegate",
+            &Account::External(*to),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn undelegate(&mut self, sender: &AccountPublicKey) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<(), Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "undelegate",
+            &(),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn delegate_scoped(
+        &mut self,
+        sender: &AccountPublicKey,
+        scope: DelegationScope,
+        to: &AccountPublicKey,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<(DelegationScope, Account), Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "delegate_scoped",
+            &(scope, Account::External(*to)),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn undelegate_scoped(
+        &mut self,
+        sender: &AccountPublicKey,
+        scope: DelegationScope,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<DelegationScope, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "undelegate_scoped",
+            &scope,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn propose_admin(
+        &mut self,
+        sender: &AccountPublicKey,
+        new_admin: &AccountPublicKey,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<Account, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "propose_admin",
+            &Account::External(*new_admin),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn accept_admin(
+        &mut self,
+        sender: &AccountPublicKey,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<(), Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "accept_admin",
+            &(),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn cancel_admin_proposal(
+        &mut self,
+        sender: &AccountPublicKey,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<(), Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "cancel_admin_proposal",
+            &(),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn execute(
+        &mut self,
+        sender: &AccountPublicKey,
+        id: u32,
+    ) -> Result<CallReceipt<Vec<ActionResult>>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<u32, Result<Vec<ActionResult>, VoteError>>(
+            VOTE_CONTRACT_ID,
+            "execute",
+            &id,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn queue(
+        &mut self,
+        sender: &AccountPublicKey,
+        id: u32,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<u32, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "queue",
+            &id,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn cancel_queued(
+        &mut self,
+        sender: &AccountPublicKey,
+        id: u32,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<u32, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "cancel_queued",
+            &id,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn set_timelock(
+        &mut self,
+        sender: &AccountPublicKey,
+        execution_delay: u64,
+        grace_period: u64,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<(u64, u64), Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "set_timelock",
+            &(execution_delay, grace_period),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn set_committee(
+        &mut self,
+        sender: &AccountPublicKey,
+        members: &[AccountPublicKey],
+        threshold: u32,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let committee = Committee { members: members.to_vec(), threshold };
+        let receipt = self.session.call::<Committee, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "set_committee",
+            &committee,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn submit_operation(
+        &mut self,
+        sender: &AccountPublicKey,
+        operation: AdminOperation,
+    ) -> Result<CallReceipt<u32>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<AdminOperation, Result<u32, VoteError>>(
+            VOTE_CONTRACT_ID,
+            "submit_operation",
+            &operation,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn approve_operation(
+        &mut self,
+        sender: &AccountPublicKey,
+        id: u32,
+    ) -> Result<CallReceipt<bool>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<u32, Result<bool, VoteError>>(
+            VOTE_CONTRACT_ID,
+            "approve_operation",
+            &id,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn cancel_operation(
+        &mut self,
+        sender: &AccountPublicKey,
+        id: u32,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<u32, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "cancel_operation",
+            &id,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn pause(
+        &mut self,
+        sender: &AccountPublicKey,
+        reason: &str,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<String, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "pause",
+            &reason.to_string(),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn unpause(
+        &mut self,
+        sender: &AccountPublicKey,
+        reason: &str,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<String, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "unpause",
+            &reason.to_string(),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn freeze(
+        &mut self,
+        sender: &AccountPublicKey,
+        successor: ContractId,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<ContractId, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "freeze",
+            &successor,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    /// Import the frozen vote contract's state into the successor
+    pub fn migrate_to_successor(
+        &mut self,
+        sender: &AccountPublicKey,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<ContractId, Result<(), VoteError>>(
+            SUCCESSOR_CONTRACT_ID,
+            "migrate_from",
+            &VOTE_CONTRACT_ID,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn grant_role(
+        &mut self,
+        sender: &AccountPublicKey,
+        role: Role,
+        pk: &AccountPublicKey,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<(Role, Account), Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "grant_role",
+            &(role, Account::External(*pk)),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn revoke_role(
+        &mut self,
+        sender: &AccountPublicKey,
+        role: Role,
+        pk: &AccountPublicKey,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<(Role, Account), Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "revoke_role",
+            &(role, Account::External(*pk)),
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn set_submission_policy(
+        &mut self,
+        sender: &AccountPublicKey,
+        policy: SubmissionPolicy,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<SubmissionPolicy, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "set_submission_policy",
+            &policy,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn allow_contract_voter(
+        &mut self,
+        sender: &AccountPublicKey,
+        contract: ContractId,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<ContractId, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "allow_contract_voter",
+            &contract,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn remove_contract_voter(
+        &mut self,
+        sender: &AccountPublicKey,
+        contract: ContractId,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<ContractId, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "remove_contract_voter",
+            &contract,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    /// Call `function` through the caller mock, so the vote contract sees it as the sender
+    pub fn call_as_contract<A>(&mut self, function: &str, args: &A) -> Result<(), VoteError>
+    where
+        A: Serialize<AllocSerializer<256>>,
+    {
+        let args = rkyv::to_bytes::<_, 256>(args).expect("Encoding arguments should succeed");
+        let data = self
+            .session
+            .call::<(ContractId, String, Vec<u8>), Vec<u8>>(
+                CALLER_CONTRACT_ID,
+                "call",
+                &(VOTE_CONTRACT_ID, function.to_string(), args.to_vec()),
+                GAS_LIMIT,
+            )
+            .expect("Caller contract call should not trap")
+            .data;
+        rkyv::from_bytes(&data).expect("Call result should deserialize")
+    }
+
+    /// Have the caller mock call `function` on the vote contract whenever its supply is read
+    pub fn set_caller_callback<A>(&mut self, function: &str, args: &A)
+    where
+        A: Serialize<AllocSerializer<256>>,
+    {
+        let args = rkyv::to_bytes::<_, 256>(args).expect("Encoding arguments should succeed");
+        self.session
+            .call::<(ContractId, String, Vec<u8>), ()>(
+                CALLER_CONTRACT_ID,
+                "set_callback",
+                &(VOTE_CONTRACT_ID, function.to_string(), args.to_vec()),
+                GAS_LIMIT,
+            )
+            .expect("Setting the callback should succeed");
+    }
+
+    pub fn set_voting_power_strategy(
+        &mut self,
+        sender: &AccountPublicKey,
+        strategy: VotingPowerStrategy,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<VotingPowerStrategy, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "set_voting_power_strategy",
+            &strategy,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn set_archive_age(
+        &mut self,
+        sender: &AccountPublicKey,
+        archive_age: u64,
+    ) -> Result<CallReceipt<()>, VoteError> {
+        self.set_sender(sender);
+        let receipt = self.session.call::<u64, Result<(), VoteError>>(
+            VOTE_CONTRACT_ID,
+            "set_archive_age",
+            &archive_age,
+            GAS_LIMIT,
+        );
+        vote_result(receipt)
+    }
+
+    pub fn archive_proposals(&mut self, sender: &AccountPublicKey, limit: u32) -> CallReceipt<u32> {
+        self.set_sender(sender);
+        self.session
+            .call::<u32, u32>(VOTE_CONTRACT_ID, "archive_proposals", &limit, GAS_LIMIT)
+            .expect("Archiving proposals should succeed")
+    }
+
+    /// Set a token balance, checkpointed at the current block height
+    pub fn set_token_balance(&mut self, pk: &AccountPublicKey, balance: u64) {
+        self.session
+            .call::<(AccountPublicKey, u64), ()>(
+                TOKEN_CONTRACT_ID,
+                "set_balance",
+                &(*pk, balance),
+                GAS_LIMIT,
+            )
+            .expect("Setting balance should succeed");
+    }
+
+    /// Set the value a key has staked with the mock stake contract
+    pub fn set_stake(&mut self, pk: &AccountPublicKey, value: u64) {
+        self.session
+            .call::<(AccountPublicKey, u64), ()>(
+                STAKE_CONTRACT,
+                "set_stake",
+                &(*pk, value),
+                GAS_LIMIT,
+            )
+            .expect("Setting stake should succeed");
+    }
+
+    /// Register a key with the mock registry
+    pub fn register(&mut self, pk: &AccountPublicKey) {
+        self.session
+            .call::<Account, ()>(
+                REGISTRY_CONTRACT_ID,
+                "register",
+                &Account::External(*pk),
+                GAS_LIMIT,
+            )
+            .expect("Registering should succeed");
+    }
+
+    /// Set a contract's token balance, checkpointed at the current block height
+    pub fn set_contract_balance(&mut self, contract: ContractId, balance: u64) {
+        self.session
+            .call::<(Account, u64), ()>(
+                TOKEN_CONTRACT_ID,
+                "set_account_balance",
+                &(Account::Contract(contract), balance),
+                GAS_LIMIT,
+            )
+            .expect("Setting balance should succeed");
     }
 
     // Helper methods for query functions
@@ -121,6 +973,43 @@ impl TestSession {
             .data
     }
 
+    pub fn get_proposals(
+        &mut self,
+        offset: u32,
+        limit: u32,
+        filter: ProposalFilter,
+        newest_first: bool,
+    ) -> Vec<Proposal> {
+        self.session
+            .call::<(u32, u32, ProposalFilter, bool), Vec<Proposal>>(
+                VOTE_CONTRACT_ID,
+                "get_proposals",
+                &(offset, limit, filter, newest_first),
+                GAS_LIMIT,
+            )
+            .expect("Querying proposals should succeed")
+            .data
+    }
+
+    pub fn get_archived_proposal(&mut self, id: u32) -> Option<ProposalRecord> {
+        self.session
+            .call::<u32, Option<ProposalRecord>>(
+                VOTE_CONTRACT_ID,
+                "get_archived_proposal",
+                &id,
+                GAS_LIMIT,
+            )
+            .expect("Querying archived proposal should succeed")
+            .data
+    }
+
+    pub fn proposal_status(&mut self, id: u32) -> Option<ProposalStatus> {
+        self.session
+            .call::<u32, Option<ProposalStatus>>(VOTE_CONTRACT_ID, "proposal_status", &id, GAS_LIMIT)
+            .expect("Getting proposal status should succeed")
+            .data
+    }
+
     pub fn get_all_proposals(&mut self) -> Vec<Proposal> {
         self.session
             .call::<(), Vec<Proposal>>(VOTE_CONTRACT_ID, "get_all_proposals", &(), GAS_LIMIT)
@@ -142,6 +1031,171 @@ impl TestSession {
             .data
     }
 
+    pub fn token_balance_at(&mut self, pk: &AccountPublicKey, height: u64) -> u64 {
+        self.session
+            .call::<(AccountPublicKey, u64), u64>(
+                TOKEN_CONTRACT_ID,
+                "balance_of_at",
+                &(*pk, height),
+                GAS_LIMIT,
+            )
+            .expect("Getting historical balance should succeed")
+            .data
+    }
+
+    pub fn get_voting_power(&mut self, pk: &AccountPublicKey, id: u32) -> u64 {
+        self.account_voting_power(Account::External(*pk), id)
+    }
+
+    pub fn get_round(&mut self, round_id: u32) -> Option<Round> {
+        self.session
+            .call::<u32, Option<Round>>(VOTE_CONTRACT_ID, "get_round", &round_id, GAS_LIMIT)
+            .expect("Getting round should succeed")
+            .data
+    }
+
+    pub fn get_election(&mut self, id: u32) -> Option<Election> {
+        self.session
+            .call::<u32, Option<Election>>(VOTE_CONTRACT_ID, "get_election", &id, GAS_LIMIT)
+            .expect("Getting election should succeed")
+            .data
+    }
+
+    pub fn remaining_credits(&mut self, pk: &AccountPublicKey, round_id: u32) -> u64 {
+        self.session
+            .call::<(Account, u32), u64>(
+                VOTE_CONTRACT_ID,
+                "remaining_credits",
+                &(Account::External(*pk), round_id),
+                GAS_LIMIT,
+            )
+            .expect("Getting remaining credits should succeed")
+            .data
+    }
+
+    pub fn account_voting_power(&mut self, account: Account, id: u32) -> u64 {
+        self.session
+            .call::<(Account, u32), u64>(
+                VOTE_CONTRACT_ID,
+                "get_voting_power",
+                &(account, id),
+                GAS_LIMIT,
+            )
+            .expect("Getting voting power should succeed")
+            .data
+    }
+
+    pub fn contract_voters(&mut self) -> Vec<ContractId> {
+        self.session
+            .call::<(), Vec<ContractId>>(VOTE_CONTRACT_ID, "contract_voters", &(), GAS_LIMIT)
+            .expect("Getting contract voters should succeed")
+            .data
+    }
+
+    pub fn get_ballot(&mut self, pk: &AccountPublicKey, id: u32) -> Option<Ballot> {
+        self.session
+            .call::<(Account, u32), Option<Ballot>>(
+                VOTE_CONTRACT_ID,
+                "get_ballot",
+                &(Account::External(*pk), id),
+                GAS_LIMIT,
+            )
+            .expect("Getting ballot should succeed")
+            .data
+    }
+
+    pub fn is_paused(&mut self) -> bool {
+        self.session
+            .call::<(), bool>(VOTE_CONTRACT_ID, "is_paused", &(), GAS_LIMIT)
+            .expect("Checking pause should succeed")
+            .data
+    }
+
+    pub fn successor(&mut self) -> Option<ContractId> {
+        self.session
+            .call::<(), Option<ContractId>>(VOTE_CONTRACT_ID, "successor", &(), GAS_LIMIT)
+            .expect("Getting successor should succeed")
+            .data
+    }
+
+    pub fn committee(&mut self) -> Option<Committee> {
+        self.session
+            .call::<(), Option<Committee>>(VOTE_CONTRACT_ID, "committee", &(), GAS_LIMIT)
+            .expect("Getting committee should succeed")
+            .data
+    }
+
+    pub fn get_operation(&mut self, id: u32) -> Option<PendingOperation> {
+        self.session
+            .call::<u32, Option<PendingOperation>>(
+                VOTE_CONTRACT_ID,
+                "get_operation",
+                &id,
+                GAS_LIMIT,
+            )
+            .expect("Getting operation should succeed")
+            .data
+    }
+
+    pub fn has_role(&mut self, role: Role, pk: &AccountPublicKey) -> bool {
+        self.session
+            .call::<(Role, Account), bool>(
+                VOTE_CONTRACT_ID,
+                "has_role",
+                &(role, Account::External(*pk)),
+                GAS_LIMIT,
+            )
+            .expect("Checking role should succeed")
+            .data
+    }
+
+    pub fn role_members(&mut self, role: Role) -> Vec<Account> {
+        self.session
+            .call::<Role, Vec<Account>>(VOTE_CONTRACT_ID, "role_members", &role, GAS_LIMIT)
+            .expect("Getting role members should succeed")
+            .data
+    }
+
+    pub fn delegate_of(
+        &mut self,
+        pk: &AccountPublicKey,
+        scope: DelegationScope,
+    ) -> Option<Account> {
+        self.session
+            .call::<(Account, DelegationScope), Option<Account>>(
+                VOTE_CONTRACT_ID,
+                "delegate_of",
+                &(Account::External(*pk), scope),
+                GAS_LIMIT,
+            )
+            .expect("Getting delegate should succeed")
+            .data
+    }
+
+    pub fn delegators_of(&mut self, pk: &AccountPublicKey, scope: DelegationScope) -> Vec<Account> {
+        self.session
+            .call::<(Account, DelegationScope), Vec<Account>>(
+                VOTE_CONTRACT_ID,
+                "delegators_of",
+                &(Account::External(*pk), scope),
+                GAS_LIMIT,
+            )
+            .expect("Getting delegators should succeed")
+            .data
+    }
+
+    pub fn effective_delegate(&mut self, pk: &AccountPublicKey, id: u32) -> Option<Account> {
+        self.session
+            .call::<(Account, u32), Option<Account>>(
+                VOTE_CONTRACT_ID,
+                "effective_delegate",
+                &(Account::External(*pk), id),
+                GAS_LIMIT,
+            )
+            .expect("Getting effective delegate should succeed")
+            .data
+    }
+
     pub fn get_balance(&mut self, pk: &AccountPublicKey) -> u64 {
         self.session
             .call::<AccountPublicKey, u64>(VOTE_CONTRACT_ID, "get_balance", pk, GAS_LIMIT)
@@ -159,3 +1213,6 @@ impl TestSession {
 
 #[cfg(test)]
 mod tests;
+
+#[cfg(test)]
+mod bench;
@@ -1,15 +1,26 @@
 //! Tests for the Vote Contract
 //!
-//! Note: Tests that require sender authentication (add_proposal, vote, etc.)
-//! need a full network session with signed transactions. Direct piecrust calls
-//! don't set up the public_sender context correctly.
-//!
 //! These tests focus on:
 //! 1. Contract deployment and initialization
 //! 2. Cross-contract calls (vote contract -> token contract)
 //! 3. Query functions that don't require authentication
+//! 4. Authenticated calls, with the sender set through session metadata
+
+use dusk_core::abi::ContractId;
+use dusk_core::signatures::bls::PublicKey as AccountPublicKey;
 
-use crate::{TestSession, TOKEN_CONTRACT_ID};
+use crate::{
+    events, Account, Action, ActionResult, AdminAccepted, AdminOperation, AdminProposed,
+    AdminTransferCancelled, Ballot, Choice, Committee, CommitteeUpdated, ContractFrozen,
+    ContractVoterChanged, DelegationChanged, DelegationScope, ElectionCreated, ElectionFinalized,
+    NewElection, NewProposal, OperationApproved, OperationClosed, PauseChanged, PowerSource,
+    PowerTransform, Proposal, ProposalArchived, ProposalClosed, ProposalCreated, ProposalExecuted,
+    ProposalFilter, ProposalOutcome, ProposalQueued, ProposalStatus, Quorum, Role, RoleChanged,
+    RoundCreated, SubmissionPolicy, SubmissionPolicyUpdated, TestSession, Threshold, TokenWeight,
+    VoteCast, VoteError, VoteOption, VotingPowerStrategy, VotingPowerStrategyUpdated, VotingRules,
+    BONUS_TOKEN_CONTRACT_ID, CALLER_CONTRACT_ID, GAS_LIMIT, REGISTRY_CONTRACT_ID,
+    SUCCESSOR_CONTRACT_ID, TOKEN_CONTRACT_ID, VOTE_CONTRACT_ID,
+};
 
 // ==================== Deployment Tests ====================
 
@@ -97,25 +108,1671 @@ fn test_get_all_proposals_empty() {
     assert!(proposals.is_empty());
 }
 
-// ==================== Note on Authentication Tests ====================
-//
-// The following functions require sender authentication via sender_account():
-// - add_proposal (admin only)
-// - close_proposal (admin only)
-// - vote (requires public_sender for token balance lookup)
-// - propose_admin (admin only)
-// - accept_admin (pending admin only)
-// - cancel_admin_proposal (admin only)
-// - is_admin (uses sender_account)
-// - has_voted (uses sender_account)
-// - get_vote_weight (uses sender_account)
-//
-// These cannot be properly tested with direct piecrust calls because
-// abi::public_sender() and abi::callstack() require transaction context.
-//
-// To fully test these functions, use the EMT-style test framework with:
-// 1. NetworkSession from dusk-vm
-// 2. Genesis contracts (transfer_contract.wasm, stake_contract.wasm)
-// 3. Signed moonlight transactions (icc_transaction)
-//
-// See: https://github.com/dusk-network/electronic-money-token/tree/main/tests
+#[test]
+fn test_get_proposals_newest_first_by_status() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+
+    for start_height in [0, 10, 0, 10, 0] {
+        session
+            .add_proposal(&admin, "Page test", start_height, 20, None)
+            .expect("Adding proposal should succeed");
+    }
+
+    let active = ProposalFilter {
+        status: Some(ProposalStatus::Active),
+        ..ProposalFilter::default()
+    };
+    let page = session.get_proposals(0, 2, active.clone(), true);
+    assert_eq!(page.iter().map(|p| p.id).collect::<Vec<_>>(), vec![4, 2]);
+    let page = session.get_proposals(2, 2, active, true);
+    assert_eq!(page.iter().map(|p| p.id).collect::<Vec<_>>(), vec![0]);
+
+    let by_admin = ProposalFilter {
+        creator: Some(Account::External(admin)),
+        ..ProposalFilter::default()
+    };
+    assert_eq!(session.get_proposals(0, 10, by_admin, false).len(), 5);
+}
+
+// ==================== Balance Snapshot Tests ====================
+
+#[test]
+fn test_token_checkpoints() {
+    let mut session = TestSession::new();
+
+    session.set_block_height(10);
+    session.set_token_balance(&*TestSession::PK_VOTER1, 50);
+
+    assert_eq!(session.token_balance(&*TestSession::PK_VOTER1), 50);
+    assert_eq!(session.token_balance_at(&*TestSession::PK_VOTER1, 9), 500);
+    assert_eq!(session.token_balance_at(&*TestSession::PK_VOTER1, 10), 50);
+}
+
+#[test]
+fn test_moved_tokens_cannot_vote_twice() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let voter2 = *TestSession::PK_VOTER2;
+
+    session.set_block_height(10);
+    let id = session
+        .add_proposal(&admin, "Snapshot test", 10, 100, None)
+        .expect("Adding proposal should succeed")
+        .data;
+
+    // Voter 1 votes, then moves their whole balance to voter 2
+    session.set_block_height(11);
+    session.vote(&voter1, id, true).expect("Voting should succeed");
+    session.set_token_balance(&voter1, 0);
+    session.set_token_balance(&voter2, 700);
+
+    // Voter 2 only gets the weight they held at the snapshot
+    assert_eq!(session.get_voting_power(&voter2, id), 200);
+    session.vote(&voter2, id, true).expect("Voting should succeed");
+
+    let proposal = session.get_proposal(id).expect("Proposal should exist");
+    assert_eq!(proposal.yes_votes, 700);
+}
+
+#[test]
+fn test_tokens_received_after_snapshot_cannot_vote() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let latecomer = *TestSession::PK_NO_TOKENS;
+
+    session.set_block_height(10);
+    let id = session
+        .add_proposal(&admin, "Snapshot test", 10, 100, None)
+        .expect("Adding proposal should succeed")
+        .data;
+
+    session.set_block_height(11);
+    session.set_token_balance(&latecomer, 1000);
+
+    assert_eq!(session.vote(&latecomer, id, true).err(), Some(VoteError::NoVotingPower));
+}
+
+// ==================== Quorum and Threshold Tests ====================
+
+#[test]
+fn test_supply_quorum_not_met() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+
+    // Total supply is 1700, so a 50% quorum needs 850
+    let rules = VotingRules {
+        quorum: Quorum::SupplyBps(5_000),
+        ..VotingRules::DEFAULT
+    };
+    session.set_block_height(10);
+    let id = session
+        .add_proposal(&admin, "Quorum test", 10, 100, Some(rules))
+        .expect("Adding proposal should succeed")
+        .data;
+
+    session.set_block_height(11);
+    session.vote(&*TestSession::PK_VOTER1, id, true).expect("Voting should succeed");
+
+    session.set_block_height(100);
+    let outcome = session
+        .close_proposal(&admin, id)
+        .expect("Closing proposal should succeed")
+        .data;
+    assert_eq!(outcome, ProposalOutcome::QuorumNotMet);
+
+    let proposal = session.get_proposal(id).expect("Proposal should exist");
+    assert_eq!(proposal.outcome, Some(ProposalOutcome::QuorumNotMet));
+    assert_eq!(proposal.status, ProposalStatus::Defeated);
+}
+
+#[test]
+fn test_supermajority_rejects_simple_majority() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+
+    let rules = VotingRules {
+        threshold: Threshold::SUPERMAJORITY,
+        ..VotingRules::DEFAULT
+    };
+    session.set_block_height(10);
+    let id = session
+        .add_proposal(&admin, "Threshold test", 10, 100, Some(rules))
+        .expect("Adding proposal should succeed")
+        .data;
+
+    // 1000 yes vs 700 no is a simple majority (~59%) but short of two thirds
+    session.set_block_height(11);
+    session.vote(&*TestSession::PK_VOTER1, id, false).expect("Voting should succeed");
+    session.vote(&*TestSession::PK_VOTER2, id, false).expect("Voting should succeed");
+    session.vote(&admin, id, true).expect("Voting should succeed");
+
+    session.set_block_height(100);
+    let outcome = session
+        .close_proposal(&admin, id)
+        .expect("Closing proposal should succeed")
+        .data;
+    assert_eq!(outcome, ProposalOutcome::Rejected);
+}
+
+// ==================== Lifecycle Tests ====================
+
+#[test]
+fn test_status_follows_voting_window() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+
+    let id = session
+        .add_proposal(&admin, "Lifecycle test", 10, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+    assert_eq!(session.proposal_status(id), Some(ProposalStatus::Pending));
+    assert_eq!(session.vote(&admin, id, true).err(), Some(VoteError::VotingNotStarted));
+
+    session.set_block_height(10);
+    assert_eq!(session.proposal_status(id), Some(ProposalStatus::Active));
+    session.vote(&admin, id, true).expect("Voting should succeed");
+
+    // Outcome is visible as soon as voting ends, and recorded on close
+    session.set_block_height(20);
+    assert_eq!(session.proposal_status(id), Some(ProposalStatus::Succeeded));
+    assert_eq!(
+        session.vote(&*TestSession::PK_VOTER1, id, false).err(),
+        Some(VoteError::VotingEnded)
+    );
+
+    session.close_proposal(&admin, id).expect("Closing proposal should succeed");
+    assert_eq!(session.proposal_status(id), Some(ProposalStatus::Succeeded));
+    assert_eq!(session.close_proposal(&admin, id).err(), Some(VoteError::ProposalAlreadyFinalized));
+}
+
+#[test]
+fn test_cancelled_proposal_rejects_votes() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+
+    let id = session
+        .add_proposal(&admin, "Cancel test", 0, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+    assert_eq!(
+        session.cancel_proposal(&*TestSession::PK_VOTER1, id).err(),
+        Some(VoteError::MissingRole)
+    );
+    session.cancel_proposal(&admin, id).expect("Cancelling proposal should succeed");
+
+    assert_eq!(session.proposal_status(id), Some(ProposalStatus::Cancelled));
+    assert_eq!(session.vote(&admin, id, true).err(), Some(VoteError::ProposalCancelled));
+
+    session.set_block_height(20);
+    assert_eq!(session.close_proposal(&admin, id).err(), Some(VoteError::ProposalCancelled));
+    assert_eq!(session.proposal_status(id), Some(ProposalStatus::Cancelled));
+}
+
+#[test]
+fn test_permissionless_proposals_need_threshold() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let voter2 = *TestSession::PK_VOTER2;
+
+    assert_eq!(
+        session.add_proposal(&voter1, "Community", 0, 20, None).err(),
+        Some(VoteError::MissingRole)
+    );
+
+    let policy = SubmissionPolicy {
+        permissionless: true,
+        threshold: 300,
+        ..SubmissionPolicy::default()
+    };
+    assert_eq!(
+        session.set_submission_policy(&voter1, policy).err(),
+        Some(VoteError::MissingRole)
+    );
+    let receipt = session
+        .set_submission_policy(&admin, policy)
+        .expect("Setting submission policy should succeed");
+    assert_eq!(
+        events::<_, SubmissionPolicyUpdated>(&receipt, SubmissionPolicyUpdated::TOPIC),
+        vec![SubmissionPolicyUpdated { policy }]
+    );
+
+    // voter1 holds 500 stDUSK and voter2 only 200
+    assert_eq!(
+        session.add_proposal(&voter2, "Community", 0, 20, None).err(),
+        Some(VoteError::BelowProposalThreshold)
+    );
+    let id = session
+        .add_proposal(&voter1, "Community", 0, 20, None)
+        .expect("Adding proposal above the threshold should succeed")
+        .data;
+    let proposal = session.get_proposal(id).expect("Proposal should exist");
+    assert_eq!(proposal.creator, Account::External(voter1));
+
+    // The balance must be strictly above the threshold
+    let at_balance = SubmissionPolicy { threshold: 200, ..policy };
+    session.set_submission_policy(&admin, at_balance).expect("Setting policy should succeed");
+    assert_eq!(
+        session.add_proposal(&voter2, "Community", 0, 20, None).err(),
+        Some(VoteError::BelowProposalThreshold)
+    );
+    let below_balance = SubmissionPolicy { threshold: 199, ..policy };
+    session.set_submission_policy(&admin, below_balance).expect("Setting policy should succeed");
+    session
+        .add_proposal(&voter2, "Community", 0, 20, None)
+        .expect("Adding proposal above the threshold should succeed");
+
+    // Without the Proposer role the global rules apply and actions are refused
+    let lax_rules = VotingRules { quorum: Quorum::Absolute(0), ..VotingRules::DEFAULT };
+    assert_eq!(
+        session.add_proposal(&voter1, "Community", 0, 20, Some(lax_rules)).err(),
+        Some(VoteError::ProposerOnly)
+    );
+
+    // Deposits are refused until a treasury is configured
+    let with_deposit = NewProposal {
+        description: "Deposit".to_string(),
+        start_height: 0,
+        end_height: 20,
+        deposit: 10,
+        ..NewProposal::default()
+    };
+    assert_eq!(
+        session.add_proposal_with(&voter1, with_deposit).err(),
+        Some(VoteError::DepositNotAccepted)
+    );
+}
+
+// ==================== Ballot Option Tests ====================
+
+#[test]
+fn test_abstain_reaches_quorum_without_approving() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+
+    // Quorum of 1500 can only be reached with the admin's abstention
+    let rules = VotingRules {
+        quorum: Quorum::Absolute(1_500),
+        ..VotingRules::DEFAULT
+    };
+    let id = session
+        .add_proposal(&admin, "Abstain test", 0, 20, Some(rules))
+        .expect("Adding proposal should succeed")
+        .data;
+
+    session.set_block_height(1);
+    session
+        .cast_vote(&admin, id, VoteOption::Abstain)
+        .expect("Voting should succeed");
+    session
+        .cast_vote(&*TestSession::PK_VOTER1, id, VoteOption::Yes)
+        .expect("Voting should succeed");
+    session
+        .cast_vote(&*TestSession::PK_VOTER2, id, VoteOption::No)
+        .expect("Voting should succeed");
+
+    session.set_block_height(20);
+    let outcome = session
+        .close_proposal(&admin, id)
+        .expect("Closing proposal should succeed")
+        .data;
+    assert_eq!(outcome, ProposalOutcome::Passed);
+
+    let proposal = session.get_proposal(id).expect("Proposal should exist");
+    assert_eq!(proposal.yes_votes, 500);
+    assert_eq!(proposal.no_votes, 200);
+    assert_eq!(proposal.abstain_votes, 1000);
+    assert_eq!(proposal.no_with_veto_votes, 0);
+}
+
+#[test]
+fn test_veto_defeats_yes_majority() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+
+    let id = session
+        .add_proposal(&admin, "Veto test", 0, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+
+    // 1500 yes against 200 veto, with a veto share lowered to 10%
+    let rules = VotingRules {
+        veto: Threshold { numerator: 1, denominator: 10 },
+        ..VotingRules::DEFAULT
+    };
+    let vetoable = session
+        .add_proposal(&admin, "Veto test", 0, 20, Some(rules))
+        .expect("Adding proposal should succeed")
+        .data;
+
+    session.set_block_height(1);
+    for proposal_id in [id, vetoable] {
+        session.vote(&admin, proposal_id, true).expect("Voting should succeed");
+        session.vote(&*TestSession::PK_VOTER1, proposal_id, true).expect("Voting should succeed");
+        session
+            .cast_vote(&*TestSession::PK_VOTER2, proposal_id, VoteOption::NoWithVeto)
+            .expect("Voting should succeed");
+    }
+
+    session.set_block_height(20);
+    let outcome = session.close_proposal(&admin, id).expect("Closing should succeed").data;
+    assert_eq!(outcome, ProposalOutcome::Passed);
+    let outcome = session.close_proposal(&admin, vetoable).expect("Closing should succeed").data;
+    assert_eq!(outcome, ProposalOutcome::Vetoed);
+    assert_eq!(session.proposal_status(vetoable), Some(ProposalStatus::Defeated));
+}
+
+// ==================== Multi-Choice Tests ====================
+
+fn multi_choice_proposal(options: &[&str]) -> NewProposal {
+    NewProposal {
+        description: "Pick one".to_string(),
+        start_height: 0,
+        end_height: 20,
+        rules: None,
+        strategy: None,
+        options: options.iter().map(|label| label.to_string()).collect(),
+        category: String::new(),
+        actions: Vec::new(),
+        deposit: 0,
+        round: None,
+    }
+}
+
+#[test]
+fn test_multi_choice_plurality_wins() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+
+    let id = session
+        .add_proposal_with(&admin, multi_choice_proposal(&["Red", "Green", "Blue"]))
+        .expect("Adding proposal should succeed")
+        .data;
+
+    session.set_block_height(1);
+    // Yes/no ballots and unknown options are rejected
+    assert_eq!(session.vote(&voter1, id, true).err(), Some(VoteError::MultiChoiceProposal));
+    assert_eq!(session.vote_choice(&voter1, id, 3).err(), Some(VoteError::InvalidOption));
+
+    session.vote_choice(&admin, id, 2).expect("Voting should succeed");
+    session.vote_choice(&voter1, id, 0).expect("Voting should succeed");
+    session.vote_choice(&*TestSession::PK_VOTER2, id, 0).expect("Voting should succeed");
+
+    assert_eq!(
+        session.get_ballot(&voter1, id),
+        Some(Ballot { choice: Choice::MultiChoice(0), weight: 500 })
+    );
+
+    session.set_block_height(20);
+    let outcome = session
+        .close_proposal(&admin, id)
+        .expect("Closing proposal should succeed")
+        .data;
+    assert_eq!(outcome, ProposalOutcome::Passed);
+
+    let proposal = session.get_proposal(id).expect("Proposal should exist");
+    assert_eq!(proposal.option_votes, vec![700, 0, 1000]);
+    assert_eq!(proposal.winning_option, Some(2));
+}
+
+#[test]
+fn test_multi_choice_tie_is_rejected() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+
+    // Match the admin's weight so the two leading options tie
+    session.set_token_balance(&*TestSession::PK_VOTER1, 1000);
+    let id = session
+        .add_proposal_with(&admin, multi_choice_proposal(&["A", "B", "C"]))
+        .expect("Adding proposal should succeed")
+        .data;
+
+    session.set_block_height(1);
+    session.vote_choice(&admin, id, 0).expect("Voting should succeed");
+    session.vote_choice(&*TestSession::PK_VOTER1, id, 1).expect("Voting should succeed");
+
+    session.set_block_height(20);
+    let outcome = session
+        .close_proposal(&admin, id)
+        .expect("Closing proposal should succeed")
+        .data;
+    assert_eq!(outcome, ProposalOutcome::Rejected);
+    assert_eq!(session.get_proposal(id).expect("Proposal should exist").winning_option, None);
+}
+
+#[test]
+fn test_multi_choice_option_count_bounds() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+
+    assert_eq!(
+        session.add_proposal_with(&admin, multi_choice_proposal(&["Only"])).err(),
+        Some(VoteError::InvalidOptionCount)
+    );
+    let eleven = ["x"; 11];
+    assert_eq!(
+        session.add_proposal_with(&admin, multi_choice_proposal(&eleven)).err(),
+        Some(VoteError::InvalidOptionCount)
+    );
+    assert!(session.add_proposal_with(&admin, multi_choice_proposal(&["x"; 10])).is_ok());
+}
+
+// ==================== Vote Change Tests ====================
+
+#[test]
+fn test_change_vote_flips_outcome() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+
+    let id = session
+        .add_proposal(&admin, "Change test", 0, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+
+    session.set_block_height(1);
+    assert_eq!(
+        session.change_vote(&voter1, id, Choice::Standard(VoteOption::No)).err(),
+        Some(VoteError::NotVoted)
+    );
+    session.vote(&admin, id, true).expect("Voting should succeed");
+    session.vote(&voter1, id, false).expect("Voting should succeed");
+
+    // The admin switches sides, taking their full weight with them
+    session
+        .change_vote(&admin, id, Choice::Standard(VoteOption::No))
+        .expect("Changing vote should succeed");
+    let proposal = session.get_proposal(id).expect("Proposal should exist");
+    assert_eq!(proposal.yes_votes, 0);
+    assert_eq!(proposal.no_votes, 1500);
+
+    // Multi-choice ballots do not fit a yes/no proposal
+    assert_eq!(
+        session.change_vote(&admin, id, Choice::MultiChoice(0)).err(),
+        Some(VoteError::NotMultiChoice)
+    );
+
+    session.set_block_height(20);
+    assert_eq!(
+        session.change_vote(&admin, id, Choice::Standard(VoteOption::Yes)).err(),
+        Some(VoteError::VotingEnded)
+    );
+    assert_eq!(session.proposal_status(id), Some(ProposalStatus::Defeated));
+}
+
+#[test]
+fn test_retract_vote_allows_recast() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+
+    let id = session
+        .add_proposal_with(&admin, multi_choice_proposal(&["A", "B"]))
+        .expect("Adding proposal should succeed")
+        .data;
+
+    session.set_block_height(1);
+    session.vote_choice(&voter1, id, 0).expect("Voting should succeed");
+    session.retract_vote(&voter1, id).expect("Retracting vote should succeed");
+    assert!(session.get_ballot(&voter1, id).is_none());
+    assert_eq!(session.retract_vote(&voter1, id).err(), Some(VoteError::NotVoted));
+
+    session.vote_choice(&voter1, id, 1).expect("Voting again should succeed");
+    let proposal = session.get_proposal(id).expect("Proposal should exist");
+    assert_eq!(proposal.option_votes, vec![0, 500]);
+}
+
+// ==================== Delegation Tests ====================
+
+#[test]
+fn test_delegate_votes_with_delegator_weight() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let voter2 = *TestSession::PK_VOTER2;
+
+    session.delegate(&voter2, &voter1).expect("Delegating should succeed");
+    assert_eq!(
+        session.delegate_of(&voter2, DelegationScope::Global),
+        Some(Account::External(voter1))
+    );
+    assert_eq!(
+        session.delegators_of(&voter1, DelegationScope::Global),
+        vec![Account::External(voter2)]
+    );
+
+    let id = session
+        .add_proposal(&admin, "Delegation test", 0, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+    assert_eq!(session.get_voting_power(&voter1, id), 700);
+
+    session.set_block_height(1);
+    session.vote(&voter1, id, true).expect("Voting should succeed");
+    assert_eq!(session.get_proposal(id).expect("Proposal should exist").yes_votes, 700);
+
+    // A direct vote overrides the delegation for this proposal
+    session.vote(&voter2, id, false).expect("Voting should succeed");
+    let proposal = session.get_proposal(id).expect("Proposal should exist");
+    assert_eq!(proposal.yes_votes, 500);
+    assert_eq!(proposal.no_votes, 200);
+    assert_eq!(session.get_ballot(&voter1, id).expect("Ballot should exist").weight, 500);
+}
+
+#[test]
+fn test_delegation_chain_and_cycle() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let voter2 = *TestSession::PK_VOTER2;
+
+    // voter2 -> voter1 -> admin
+    session.delegate(&voter2, &voter1).expect("Delegating should succeed");
+    session.delegate(&voter1, &admin).expect("Delegating should succeed");
+    assert_eq!(session.delegate(&admin, &voter2).err(), Some(VoteError::DelegationCycle));
+    assert_eq!(session.delegate(&admin, &admin).err(), Some(VoteError::CannotDelegateSelf));
+
+    let id = session
+        .add_proposal(&admin, "Chain test", 0, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+
+    session.set_block_height(1);
+    session.vote(&admin, id, true).expect("Voting should succeed");
+    assert_eq!(session.get_proposal(id).expect("Proposal should exist").yes_votes, 1700);
+
+    // voter1 takes back their own weight and the weight delegated through them
+    session.vote(&voter1, id, false).expect("Voting should succeed");
+    let proposal = session.get_proposal(id).expect("Proposal should exist");
+    assert_eq!(proposal.yes_votes, 1000);
+    assert_eq!(proposal.no_votes, 700);
+}
+
+#[test]
+fn test_undelegated_account_votes_alone() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+
+    assert_eq!(session.undelegate(&voter1).err(), Some(VoteError::NotDelegating));
+    session.delegate(&voter1, &admin).expect("Delegating should succeed");
+    session.undelegate(&voter1).expect("Undelegating should succeed");
+    assert_eq!(session.delegate_of(&voter1, DelegationScope::Global), None);
+    assert!(session.delegators_of(&admin, DelegationScope::Global).is_empty());
+
+    let id = session
+        .add_proposal(&admin, "Undelegate test", 0, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+    assert_eq!(session.get_voting_power(&admin, id), 1000);
+}
+
+#[test]
+fn test_category_delegation_applies_to_tagged_proposals() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let voter2 = *TestSession::PK_VOTER2;
+    let treasury = DelegationScope::Category("treasury".to_string());
+
+    // voter2 follows voter1 on treasury matters and the admin on everything else
+    session.delegate(&voter2, &admin).expect("Delegating should succeed");
+    session
+        .delegate_scoped(&voter2, treasury.clone(), &voter1)
+        .expect("Delegating should succeed");
+    assert_eq!(session.delegate_of(&voter2, treasury), Some(Account::External(voter1)));
+
+    let general = session
+        .add_proposal(&admin, "General", 0, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+    let spending = session
+        .add_proposal_with(
+            &admin,
+            NewProposal {
+                description: "Spending".to_string(),
+                start_height: 0,
+                end_height: 20,
+                rules: None,
+                strategy: None,
+                options: Vec::new(),
+                category: "treasury".to_string(),
+                actions: Vec::new(),
+                deposit: 0,
+                round: None,
+            },
+        )
+        .expect("Adding proposal should succeed")
+        .data;
+    assert_eq!(session.effective_delegate(&voter2, general), Some(Account::External(admin)));
+    assert_eq!(session.effective_delegate(&voter2, spending), Some(Account::External(voter1)));
+    assert_eq!(session.get_voting_power(&admin, spending), 1000);
+    assert_eq!(session.get_voting_power(&voter1, spending), 700);
+
+    session.set_block_height(1);
+    for id in [general, spending] {
+        session.vote(&admin, id, true).expect("Voting should succeed");
+        session.vote(&voter1, id, false).expect("Voting should succeed");
+    }
+    let proposal = session.get_proposal(general).expect("Proposal should exist");
+    assert_eq!((proposal.yes_votes, proposal.no_votes), (1200, 500));
+    let proposal = session.get_proposal(spending).expect("Proposal should exist");
+    assert_eq!((proposal.yes_votes, proposal.no_votes), (1000, 700));
+}
+
+#[test]
+fn test_proposal_delegation_overrides_category() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let voter2 = *TestSession::PK_VOTER2;
+
+    let id = session
+        .add_proposal(&admin, "Override test", 0, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+    assert_eq!(
+        session.delegate_scoped(&voter2, DelegationScope::Proposal(99), &voter1).err(),
+        Some(VoteError::ProposalNotFound)
+    );
+
+    session.delegate(&voter2, &admin).expect("Delegating should succeed");
+    session
+        .delegate_scoped(&voter2, DelegationScope::Proposal(id), &voter1)
+        .expect("Delegating should succeed");
+    assert_eq!(session.get_voting_power(&voter1, id), 700);
+
+    session
+        .undelegate_scoped(&voter2, DelegationScope::Proposal(id))
+        .expect("Undelegating should succeed");
+    assert_eq!(session.get_voting_power(&voter1, id), 500);
+    assert_eq!(session.get_voting_power(&admin, id), 1200);
+}
+
+// ==================== Execution Tests ====================
+
+#[test]
+fn test_execute_performs_actions_after_timelock() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let recipient = *TestSession::PK_NO_TOKENS;
+
+    let args = rkyv::to_bytes::<_, 256>(&(recipient, 5000u64))
+        .expect("Encoding argument should succeed")
+        .to_vec();
+    let set_balance = Action {
+        target: TOKEN_CONTRACT_ID,
+        function: "set_balance".to_string(),
+        args,
+    };
+    let missing = Action {
+        target: TOKEN_CONTRACT_ID,
+        function: "missing_function".to_string(),
+        args: Vec::new(),
+    };
+
+    session.set_timelock(&admin, 5, 10).expect("Setting timelock should succeed");
+    let id = session
+        .add_proposal_with(
+            &admin,
+            NewProposal {
+                description: "Mint".to_string(),
+                start_height: 0,
+                end_height: 20,
+                actions: vec![set_balance.clone(), missing, set_balance],
+                ..NewProposal::default()
+            },
+        )
+        .expect("Adding proposal should succeed")
+        .data;
+
+    session.set_block_height(1);
+    session.vote(&admin, id, true).expect("Voting should succeed");
+    session.set_block_height(20);
+    session.close_proposal(&admin, id).expect("Closing proposal should succeed");
+    assert_eq!(session.execute(&admin, id).err(), Some(VoteError::NotQueued));
+    let receipt = session.queue(&*TestSession::PK_VOTER2, id).expect("Queueing should succeed");
+    assert_eq!(
+        events::<_, ProposalQueued>(&receipt, ProposalQueued::TOPIC),
+        vec![ProposalQueued { proposal_id: id, execute_after: 25, expires_at: 35 }]
+    );
+    assert_eq!(session.execute(&admin, id).err(), Some(VoteError::TimelockNotElapsed));
+
+    // Anyone can execute; the failing call stops the remaining actions
+    session.set_block_height(25);
+    let receipt = session
+        .execute(&*TestSession::PK_VOTER1, id)
+        .expect("Executing proposal should succeed");
+    assert_eq!(receipt.data.len(), 2);
+    assert!(matches!(receipt.data[0], ActionResult::Success(_)));
+    assert!(matches!(receipt.data[1], ActionResult::Failed(_)));
+    let executed = events::<_, ProposalExecuted>(&receipt, ProposalExecuted::TOPIC);
+    assert_eq!(executed.len(), 1);
+    assert_eq!(executed[0].results, receipt.data);
+
+    assert_eq!(session.token_balance(&recipient), 5000);
+    let proposal = session.get_proposal(id).expect("Proposal should exist");
+    assert_eq!(proposal.status, ProposalStatus::Executed);
+    assert_eq!(proposal.action_results, receipt.data);
+    assert_eq!(session.execute(&admin, id).err(), Some(VoteError::AlreadyExecuted));
+}
+
+#[test]
+fn test_canceller_cancels_queued_proposal() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let canceller = *TestSession::PK_VOTER2;
+
+    session.grant_role(&admin, Role::Canceller, &canceller).expect("Granting role should succeed");
+    session.set_timelock(&admin, 5, 10).expect("Setting timelock should succeed");
+    let action = Action {
+        target: TOKEN_CONTRACT_ID,
+        function: "total_supply_at".to_string(),
+        args: rkyv::to_bytes::<_, 16>(&0u64).expect("Encoding should succeed").to_vec(),
+    };
+    let id = session
+        .add_proposal_with(
+            &admin,
+            NewProposal {
+                description: "Guarded".to_string(),
+                start_height: 0,
+                end_height: 20,
+                actions: vec![action],
+                ..NewProposal::default()
+            },
+        )
+        .expect("Adding proposal should succeed")
+        .data;
+    session.set_block_height(1);
+    session.vote(&admin, id, true).expect("Voting should succeed");
+    session.set_block_height(20);
+    session.close_proposal(&admin, id).expect("Closing proposal should succeed");
+    session.queue(&admin, id).expect("Queueing should succeed");
+
+    assert_eq!(
+        session.cancel_queued(&*TestSession::PK_VOTER1, id).err(),
+        Some(VoteError::MissingRole)
+    );
+    session.cancel_queued(&canceller, id).expect("Canceller cancel should succeed");
+    assert_eq!(session.proposal_status(id), Some(ProposalStatus::Cancelled));
+
+    session.set_block_height(25);
+    assert_eq!(session.execute(&admin, id).err(), Some(VoteError::NotQueued));
+}
+
+#[test]
+fn test_queued_proposal_expires_after_grace_period() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+
+    session.set_timelock(&admin, 5, 10).expect("Setting timelock should succeed");
+    let action = Action {
+        target: TOKEN_CONTRACT_ID,
+        function: "total_supply_at".to_string(),
+        args: rkyv::to_bytes::<_, 16>(&0u64).expect("Encoding should succeed").to_vec(),
+    };
+    let id = session
+        .add_proposal_with(
+            &admin,
+            NewProposal {
+                description: "Stale".to_string(),
+                start_height: 0,
+                end_height: 20,
+                actions: vec![action],
+                ..NewProposal::default()
+            },
+        )
+        .expect("Adding proposal should succeed")
+        .data;
+    session.set_block_height(1);
+    session.vote(&admin, id, true).expect("Voting should succeed");
+    session.set_block_height(20);
+    session.close_proposal(&admin, id).expect("Closing proposal should succeed");
+    session.queue(&admin, id).expect("Queueing should succeed");
+
+    // Queued at 20: executable from 25 until the grace period ends at 35
+    session.set_block_height(35);
+    assert_eq!(session.execute(&admin, id).err(), Some(VoteError::ProposalExpired));
+    assert_eq!(session.proposal_status(id), Some(ProposalStatus::Expired));
+}
+
+// ==================== Archiving Tests ====================
+
+#[test]
+fn test_archived_proposal_stays_queryable() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+
+    let id = session
+        .add_proposal(&admin, "Archive test", 0, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+    session.set_block_height(1);
+    session.vote(&admin, id, true).expect("Voting should succeed");
+    session.set_block_height(20);
+    session.close_proposal(&admin, id).expect("Closing proposal should succeed");
+    session.set_archive_age(&admin, 10).expect("Setting archive age should succeed");
+
+    session.set_block_height(29);
+    assert_eq!(session.archive_proposals(&admin, 10).data, 0);
+
+    // Anyone can archive once the proposal is old enough
+    session.set_block_height(30);
+    let receipt = session.archive_proposals(&*TestSession::PK_VOTER1, 10);
+    assert_eq!(receipt.data, 1);
+    let archived = events::<_, ProposalArchived>(&receipt, ProposalArchived::TOPIC);
+    assert_eq!(archived.len(), 1);
+    assert_eq!(archived[0].voter_count, 1);
+
+    let record = session.get_archived_proposal(id).expect("Archived record should exist");
+    assert_eq!(record.votes_commitment, archived[0].votes_commitment);
+    assert_ne!(record.votes_commitment, [0u8; 32]);
+    assert_eq!(record.proposal.yes_votes, 1000);
+
+    let proposal = session.get_proposal(id).expect("Archived proposal should be queryable");
+    assert_eq!(proposal.status, ProposalStatus::Succeeded);
+    assert!(session.get_ballot(&admin, id).is_none());
+    assert_eq!(session.proposal_count(), 0);
+}
+
+// ==================== Role Tests ====================
+
+#[test]
+fn test_roles_are_granted_separately() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+
+    let receipt = session
+        .grant_role(&admin, Role::Closer, &voter1)
+        .expect("Granting role should succeed");
+    assert_eq!(
+        events::<_, RoleChanged>(&receipt, RoleChanged::GRANTED_TOPIC),
+        vec![RoleChanged { role: Role::Closer, account: Account::External(voter1) }]
+    );
+    assert!(session.has_role(Role::Closer, &voter1));
+    assert!(!session.has_role(Role::Proposer, &voter1));
+    assert_eq!(
+        session.grant_role(&voter1, Role::Proposer, &voter1).err(),
+        Some(VoteError::NotAdmin)
+    );
+
+    // A Closer can close but not create or cancel proposals
+    let id = session
+        .add_proposal(&admin, "Roles", 0, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+    assert_eq!(session.cancel_proposal(&voter1, id).err(), Some(VoteError::MissingRole));
+    session.set_block_height(20);
+    session.close_proposal(&voter1, id).expect("Closer should close the proposal");
+
+    let receipt = session
+        .revoke_role(&admin, Role::Closer, &voter1)
+        .expect("Revoking role should succeed");
+    assert_eq!(
+        events::<_, RoleChanged>(&receipt, RoleChanged::REVOKED_TOPIC),
+        vec![RoleChanged { role: Role::Closer, account: Account::External(voter1) }]
+    );
+    assert_eq!(session.role_members(Role::Closer), vec![Account::External(admin)]);
+}
+
+#[test]
+fn test_admin_transfer_hands_over_role_management() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+
+    session.propose_admin(&admin, &voter1).expect("Proposing admin should succeed");
+    session.accept_admin(&voter1).expect("Accepting admin should succeed");
+
+    // The new admin manages roles but holds none until granted
+    assert!(!session.has_role(Role::Proposer, &voter1));
+    assert_eq!(
+        session.grant_role(&admin, Role::Proposer, &admin).err(),
+        Some(VoteError::NotAdmin)
+    );
+    session.grant_role(&voter1, Role::Proposer, &voter1).expect("Granting role should succeed");
+    session.revoke_role(&voter1, Role::Proposer, &admin).expect("Revoking role should succeed");
+    assert_eq!(session.role_members(Role::Proposer), vec![Account::External(voter1)]);
+}
+
+// ==================== Pause Tests ====================
+
+#[test]
+fn test_pause_halts_voting_until_unpaused() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let pauser = *TestSession::PK_VOTER2;
+
+    let id = session
+        .add_proposal(&admin, "Pause test", 0, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+    session.set_block_height(1);
+
+    assert_eq!(session.pause(&pauser, "Exploit").err(), Some(VoteError::MissingRole));
+    session.grant_role(&admin, Role::Pauser, &pauser).expect("Granting role should succeed");
+    let receipt = session.pause(&pauser, "Token exploit").expect("Pausing should succeed");
+    assert_eq!(
+        events::<_, PauseChanged>(&receipt, PauseChanged::PAUSED_TOPIC),
+        vec![PauseChanged {
+            account: Account::External(pauser),
+            reason: "Token exploit".to_string(),
+        }]
+    );
+    assert!(session.is_paused());
+
+    assert_eq!(session.vote(&voter1, id, true).err(), Some(VoteError::Paused));
+    assert_eq!(
+        session.add_proposal(&admin, "Blocked", 0, 20, None).err(),
+        Some(VoteError::Paused)
+    );
+    assert_eq!(session.get_proposal(id).map(|p| p.status), Some(ProposalStatus::Active));
+
+    // The admin can unpause without holding the Pauser role
+    session.revoke_role(&admin, Role::Pauser, &admin).expect("Revoking role should succeed");
+    let receipt = session.unpause(&admin, "Patched").expect("Unpausing should succeed");
+    assert_eq!(
+        events::<_, PauseChanged>(&receipt, PauseChanged::UNPAUSED_TOPIC),
+        vec![PauseChanged { account: Account::External(admin), reason: "Patched".to_string() }]
+    );
+    session.vote(&voter1, id, true).expect("Voting should succeed after unpausing");
+}
+
+// ==================== Migration Tests ====================
+
+#[test]
+fn test_state_migrates_to_successor() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let voter2 = *TestSession::PK_VOTER2;
+
+    let id = session
+        .add_proposal(&admin, "Migration test", 0, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+    session.set_block_height(1);
+    session.vote(&voter1, id, true).expect("Voting should succeed");
+    session.deploy_successor();
+
+    // Only the successor can pull the state, and only once frozen
+    assert_eq!(session.migrate_to_successor(&admin).err(), Some(VoteError::NotSuccessor));
+    assert_eq!(session.freeze(&voter1, SUCCESSOR_CONTRACT_ID).err(), Some(VoteError::NotAdmin));
+    let receipt = session.freeze(&admin, SUCCESSOR_CONTRACT_ID).expect("Freezing should succeed");
+    assert_eq!(
+        events::<_, ContractFrozen>(&receipt, ContractFrozen::TOPIC),
+        vec![ContractFrozen { successor: SUCCESSOR_CONTRACT_ID }]
+    );
+    assert_eq!(session.successor(), Some(SUCCESSOR_CONTRACT_ID));
+    assert_eq!(session.vote(&voter1, id, false).err(), Some(VoteError::Frozen));
+
+    assert_eq!(session.migrate_to_successor(&voter1).err(), Some(VoteError::NotAdmin));
+    session.migrate_to_successor(&admin).expect("Migrating should succeed");
+    assert_eq!(session.migrate_to_successor(&admin).err(), Some(VoteError::StateNotEmpty));
+
+    // The old contract stays readable; the successor carries on from the same state
+    let migrated = session
+        .session
+        .call::<u32, Option<Proposal>>(SUCCESSOR_CONTRACT_ID, "get_proposal", &id, GAS_LIMIT)
+        .expect("Getting proposal should succeed")
+        .data;
+    assert_eq!(migrated, session.get_proposal(id));
+    session.set_sender(&voter1);
+    let ballot = session
+        .session
+        .call::<(Account, u32), Option<Ballot>>(
+            SUCCESSOR_CONTRACT_ID,
+            "get_ballot",
+            &(Account::External(voter1), id),
+            GAS_LIMIT,
+        )
+        .expect("Getting ballot should succeed")
+        .data;
+    assert_eq!(ballot, session.get_ballot(&voter1, id));
+    session.set_sender(&voter2);
+    let receipt = session
+        .session
+        .call::<(u32, bool), Result<(), VoteError>>(
+            SUCCESSOR_CONTRACT_ID,
+            "vote",
+            &(id, true),
+            GAS_LIMIT,
+        )
+        .expect("Vote contract call should not trap");
+    assert_eq!(receipt.data, Ok(()));
+}
+
+// ==================== Voting Power Strategy Tests ====================
+
+fn strategy_proposal(source: PowerSource, transform: PowerTransform) -> NewProposal {
+    NewProposal {
+        description: "Strategy".to_string(),
+        start_height: 0,
+        end_height: 20,
+        strategy: Some(VotingPowerStrategy { source, transform }),
+        ..NewProposal::default()
+    }
+}
+
+#[test]
+fn test_proposal_strategies_read_their_source() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let voter2 = *TestSession::PK_VOTER2;
+    let no_tokens = *TestSession::PK_NO_TOKENS;
+
+    let staked = session
+        .add_proposal_with(&admin, strategy_proposal(PowerSource::Staked, PowerTransform::Linear))
+        .expect("Adding proposal should succeed")
+        .data;
+    let tokens = vec![
+        TokenWeight { token: TOKEN_CONTRACT_ID, multiplier: 1 },
+        TokenWeight { token: BONUS_TOKEN_CONTRACT_ID, multiplier: 3 },
+    ];
+    let multi_token = session
+        .add_proposal_with(
+            &admin,
+            strategy_proposal(PowerSource::MultiToken(tokens), PowerTransform::Linear),
+        )
+        .expect("Adding proposal should succeed")
+        .data;
+    let quadratic = session
+        .add_proposal_with(
+            &admin,
+            strategy_proposal(PowerSource::SnapshotBalance, PowerTransform::SquareRoot),
+        )
+        .expect("Adding proposal should succeed")
+        .data;
+    let registry = session
+        .add_proposal_with(
+            &admin,
+            strategy_proposal(PowerSource::Registry(REGISTRY_CONTRACT_ID), PowerTransform::Linear),
+        )
+        .expect("Adding proposal should succeed")
+        .data;
+    session.set_block_height(1);
+
+    // Stake is read when the vote is cast
+    assert_eq!(session.get_voting_power(&voter1, staked), 400);
+    assert_eq!(session.vote(&admin, staked, true).err(), Some(VoteError::NoVotingPower));
+    session.set_stake(&voter1, 100);
+    session.vote(&voter1, staked, true).expect("Voting should succeed");
+    assert_eq!(session.get_proposal(staked).map(|p| p.yes_votes), Some(100));
+
+    // Each token counts with its multiplier
+    assert_eq!(session.get_voting_power(&voter1, multi_token), 500);
+    assert_eq!(session.get_voting_power(&voter2, multi_token), 200 + 3 * 50);
+
+    // Square roots damp the largest holder
+    assert_eq!(session.get_voting_power(&admin, quadratic), 31);
+    assert_eq!(session.get_voting_power(&voter2, quadratic), 14);
+
+    // One vote per registered account, whatever it holds
+    session.register(&no_tokens);
+    assert_eq!(session.get_voting_power(&admin, registry), 1);
+    assert_eq!(session.get_voting_power(&voter2, registry), 0);
+    session.vote(&no_tokens, registry, true).expect("Voting should succeed");
+    assert_eq!(session.get_proposal(registry).map(|p| p.yes_votes), Some(1));
+}
+
+#[test]
+fn test_default_strategy_applies_to_new_proposals() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let strategy =
+        VotingPowerStrategy { source: PowerSource::Staked, transform: PowerTransform::Linear };
+
+    let before = session
+        .add_proposal(&admin, "Token weighted", 0, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+    assert_eq!(
+        session.set_voting_power_strategy(&voter1, strategy.clone()).err(),
+        Some(VoteError::MissingRole)
+    );
+    let receipt = session
+        .set_voting_power_strategy(&admin, strategy.clone())
+        .expect("Setting strategy should succeed");
+    assert_eq!(
+        events::<_, VotingPowerStrategyUpdated>(&receipt, VotingPowerStrategyUpdated::TOPIC),
+        vec![VotingPowerStrategyUpdated { strategy: strategy.clone() }]
+    );
+    let after = session
+        .add_proposal(&admin, "Stake weighted", 0, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+
+    assert_eq!(
+        session.get_proposal(before).map(|p| p.strategy),
+        Some(VotingPowerStrategy::DEFAULT)
+    );
+    assert_eq!(session.get_proposal(after).map(|p| p.strategy), Some(strategy));
+    assert_eq!(session.get_voting_power(&voter1, before), 500);
+    assert_eq!(session.get_voting_power(&voter1, after), 400);
+}
+
+// ==================== Quadratic Voting Tests ====================
+
+fn round_proposal(round: u32, description: &str) -> NewProposal {
+    NewProposal {
+        description: description.to_string(),
+        start_height: 1,
+        end_height: 20,
+        round: Some(round),
+        ..NewProposal::default()
+    }
+}
+
+#[test]
+fn test_quadratic_round_spends_credits() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let voter2 = *TestSession::PK_VOTER2;
+
+    let receipt = session.create_round(&admin, 1, 20).expect("Creating round should succeed");
+    let round = receipt.data;
+    let created = events::<_, RoundCreated>(&receipt, RoundCreated::TOPIC);
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].round_id, round);
+    let first = session
+        .add_proposal_with(&admin, round_proposal(round, "Grants"))
+        .expect("Adding proposal should succeed")
+        .data;
+    let second = session
+        .add_proposal_with(&admin, round_proposal(round, "Tooling"))
+        .expect("Adding proposal should succeed")
+        .data;
+    assert_eq!(session.get_round(round).map(|r| r.proposal_ids), Some(vec![first, second]));
+    session.set_block_height(1);
+
+    // Proposals can only join before the round starts
+    assert_eq!(
+        session.add_proposal_with(&admin, round_proposal(round, "Late")).err(),
+        Some(VoteError::RoundStarted)
+    );
+
+    // 20 votes cost 400 of voter1's 500 credits
+    assert_eq!(session.remaining_credits(&voter1, round), 500);
+    session.vote_quadratic(&voter1, first, true, 20).expect("Voting should succeed");
+    assert_eq!(session.remaining_credits(&voter1, round), 100);
+    assert_eq!(
+        session.vote_quadratic(&voter1, second, true, 11).err(),
+        Some(VoteError::InsufficientCredits)
+    );
+    session.vote_quadratic(&voter1, second, true, 10).expect("Voting should succeed");
+    assert_eq!(session.remaining_credits(&voter1, round), 0);
+
+    // Recasting refunds the earlier votes before charging the new ones
+    let receipt = session
+        .vote_quadratic(&voter1, first, true, 10)
+        .expect("Changing votes should succeed");
+    let changed = events::<_, VoteCast>(&receipt, VoteCast::CHANGED_TOPIC);
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].weight, 10);
+    assert_eq!(session.remaining_credits(&voter1, round), 300);
+
+    session.vote_quadratic(&voter2, first, false, 14).expect("Voting should succeed");
+    let proposal = session.get_proposal(first).expect("Proposal should exist");
+    assert_eq!((proposal.yes_votes, proposal.no_votes), (10, 14));
+    assert_eq!(session.vote(&voter2, second, true).err(), Some(VoteError::RoundProposal));
+
+    // Zero votes withdraws the ballot and its cost
+    session.vote_quadratic(&voter1, second, true, 0).expect("Withdrawing should succeed");
+    assert_eq!(session.get_ballot(&voter1, second), None);
+    assert_eq!(session.remaining_credits(&voter1, round), 400);
+}
+
+#[test]
+fn test_round_supply_quorum_counts_votes_credits_buy() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let voter2 = *TestSession::PK_VOTER2;
+
+    // Half of the 1700 supply is 850 credits, which buy 29 votes
+    let round = session.create_round(&admin, 1, 20).expect("Creating round should succeed").data;
+    let rules = VotingRules { quorum: Quorum::SupplyBps(5_000), ..VotingRules::DEFAULT };
+    let mut ids = Vec::new();
+    for description in ["Grants", "Tooling"] {
+        let params = NewProposal { rules: Some(rules), ..round_proposal(round, description) };
+        let id = session
+            .add_proposal_with(&admin, params)
+            .expect("Adding proposal should succeed")
+            .data;
+        ids.push(id);
+    }
+    session.set_block_height(1);
+    session.vote_quadratic(&voter1, ids[0], true, 20).expect("Voting should succeed");
+    session.vote_quadratic(&voter2, ids[0], true, 14).expect("Voting should succeed");
+    session.vote_quadratic(&voter1, ids[1], true, 10).expect("Voting should succeed");
+
+    session.set_block_height(20);
+    let outcome = session.close_proposal(&admin, ids[0]).expect("Closing should succeed").data;
+    assert_eq!(outcome, ProposalOutcome::Passed);
+    let outcome = session.close_proposal(&admin, ids[1]).expect("Closing should succeed").data;
+    assert_eq!(outcome, ProposalOutcome::QuorumNotMet);
+}
+
+// ==================== Election Tests ====================
+
+fn council_election(candidates: &[AccountPublicKey], seats: u32, install: bool) -> NewElection {
+    NewElection {
+        description: "Council".to_string(),
+        start_height: 1,
+        end_height: 20,
+        candidates: candidates.iter().map(|pk| Account::External(*pk)).collect(),
+        seats,
+        install_council: install,
+    }
+}
+
+#[test]
+fn test_instant_runoff_election() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let voter2 = *TestSession::PK_VOTER2;
+    let no_tokens = *TestSession::PK_NO_TOKENS;
+
+    session.set_token_balance(&admin, 350);
+    session.set_block_height(1);
+    let receipt = session
+        .create_election(&admin, council_election(&[voter1, voter2, no_tokens], 1, false))
+        .expect("Creating election should succeed");
+    let id = receipt.data;
+    assert_eq!(events::<_, ElectionCreated>(&receipt, ElectionCreated::TOPIC).len(), 1);
+
+    session.vote_ranked(&voter1, id, &[0]).expect("Voting should succeed");
+    session.vote_ranked(&admin, id, &[2, 1]).expect("Voting should succeed");
+    session.vote_ranked(&voter2, id, &[1, 2]).expect("Voting should succeed");
+    assert_eq!(session.vote_ranked(&voter1, id, &[1]).err(), Some(VoteError::AlreadyVoted));
+    assert_eq!(session.vote_ranked(&no_tokens, id, &[0]).err(), Some(VoteError::NoVotingPower));
+    assert_eq!(session.finalize_election(&admin, id).err(), Some(VoteError::VotingNotEnded));
+
+    // Voter 1 leads with 500 of 1050, short of a majority; voter 2's
+    // ballot moves on to the third candidate once voter 2 is eliminated
+    session.set_block_height(20);
+    let winners = session
+        .finalize_election(&admin, id)
+        .expect("Finalizing election should succeed")
+        .data;
+    assert_eq!(winners, vec![Account::External(no_tokens)]);
+    let election = session.get_election(id).expect("Election should exist");
+    assert_eq!(election.total_weight, 1050);
+    assert_eq!(election.winners, winners);
+    assert!(!session.has_role(Role::Council, &no_tokens));
+}
+
+#[test]
+fn test_stv_election_installs_council() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let voter2 = *TestSession::PK_VOTER2;
+
+    session.set_token_balance(&admin, 300);
+    session.set_block_height(1);
+    let id = session
+        .create_election(&admin, council_election(&[voter1, voter2, admin], 2, true))
+        .expect("Creating election should succeed")
+        .data;
+    session.vote_ranked(&voter1, id, &[0, 1]).expect("Voting should succeed");
+    session.vote_ranked(&admin, id, &[2, 0]).expect("Voting should succeed");
+    session.vote_ranked(&voter2, id, &[1, 0]).expect("Voting should succeed");
+
+    // Voter 1's surplus over the quota of 334 carries voter 2 past the admin
+    session.set_block_height(20);
+    let receipt = session.finalize_election(&admin, id).expect("Finalizing should succeed");
+    assert_eq!(receipt.data, vec![Account::External(voter1), Account::External(voter2)]);
+    let finalized = events::<_, ElectionFinalized>(&receipt, ElectionFinalized::TOPIC);
+    assert_eq!(finalized.len(), 1);
+    assert_eq!(finalized[0].winners, receipt.data);
+    assert_eq!(events::<_, RoleChanged>(&receipt, RoleChanged::GRANTED_TOPIC).len(), 2);
+    assert!(session.has_role(Role::Council, &voter1));
+    assert!(session.has_role(Role::Council, &voter2));
+    assert!(!session.has_role(Role::Council, &admin));
+    assert_eq!(session.finalize_election(&admin, id).err(), Some(VoteError::ElectionFinalized));
+}
+
+// ==================== Contract Voter Tests ====================
+
+#[test]
+fn test_allowlisted_contract_has_voting_power() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let vault = ContractId::from_bytes([9; 32]);
+
+    session.set_block_height(5);
+    session.set_contract_balance(vault, 300);
+    session.set_block_height(10);
+    let id = session
+        .add_proposal(&admin, "Treasury vote", 10, 100, None)
+        .expect("Adding proposal should succeed")
+        .data;
+
+    // A contract off the allowlist carries no weight, whatever it holds
+    assert_eq!(session.account_voting_power(Account::Contract(vault), id), 0);
+    assert_eq!(session.allow_contract_voter(&voter1, vault).err(), Some(VoteError::MissingRole));
+    let receipt =
+        session.allow_contract_voter(&admin, vault).expect("Allowing contract should succeed");
+    assert_eq!(
+        events::<_, ContractVoterChanged>(&receipt, ContractVoterChanged::ALLOWED_TOPIC),
+        vec![ContractVoterChanged { contract: vault }]
+    );
+    assert_eq!(session.contract_voters(), vec![vault]);
+
+    // Tokens moved into the contract after the snapshot don't count
+    session.set_block_height(11);
+    session.set_contract_balance(vault, 900);
+    assert_eq!(session.account_voting_power(Account::Contract(vault), id), 300);
+
+    session.remove_contract_voter(&admin, vault).expect("Removing contract should succeed");
+    assert_eq!(session.account_voting_power(Account::Contract(vault), id), 0);
+    assert_eq!(
+        session.remove_contract_voter(&admin, vault).err(),
+        Some(VoteError::NotContractVoter)
+    );
+}
+
+#[test]
+fn test_contract_voter_needs_allowlist_to_change_vote() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let vault = CALLER_CONTRACT_ID;
+
+    session.deploy_caller();
+    session.set_block_height(5);
+    session.set_contract_balance(vault, 300);
+    session.set_block_height(10);
+    let id = session
+        .add_proposal(&admin, "Treasury vote", 10, 100, None)
+        .expect("Adding proposal should succeed")
+        .data;
+
+    assert_eq!(session.call_as_contract("vote", &(id, true)), Err(VoteError::ContractsCannotVote));
+    session.allow_contract_voter(&admin, vault).expect("Allowing contract should succeed");
+    session.call_as_contract("vote", &(id, true)).expect("Allowlisted contract should vote");
+    let proposal = session.get_proposal(id).expect("Proposal should exist");
+    assert_eq!(proposal.yes_votes, 300);
+
+    // Once off the allowlist, the contract cannot move the weight it cast
+    session.remove_contract_voter(&admin, vault).expect("Removing contract should succeed");
+    let no = Choice::Standard(VoteOption::No);
+    assert_eq!(
+        session.call_as_contract("change_vote", &(id, no)),
+        Err(VoteError::ContractsCannotVote)
+    );
+    let proposal = session.get_proposal(id).expect("Proposal should exist");
+    assert_eq!((proposal.yes_votes, proposal.no_votes), (300, 0));
+}
+
+// ==================== Committee Tests ====================
+
+#[test]
+fn test_committee_operations_need_threshold_approvals() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let voter2 = *TestSession::PK_VOTER2;
+    let committee_account = Account::Contract(VOTE_CONTRACT_ID);
+
+    let receipt = session
+        .set_committee(&admin, &[admin, voter1, voter2], 2)
+        .expect("Setting committee should succeed");
+    let committee = Committee { members: vec![admin, voter1, voter2], threshold: 2 };
+    assert_eq!(
+        events::<_, CommitteeUpdated>(&receipt, CommitteeUpdated::TOPIC),
+        vec![CommitteeUpdated { committee: committee.clone() }]
+    );
+    assert_eq!(session.committee(), Some(committee));
+
+    // No single key can act as the admin any more
+    assert_eq!(
+        session.add_proposal(&admin, "Solo", 0, 20, None).err(),
+        Some(VoteError::MissingRole)
+    );
+    assert_eq!(session.set_committee(&admin, &[admin], 1).err(), Some(VoteError::NotAdmin));
+
+    let operation = AdminOperation::AddProposal(NewProposal {
+        description: "Committee proposal".to_string(),
+        start_height: 0,
+        end_height: 20,
+        ..NewProposal::default()
+    });
+    let op = session
+        .submit_operation(&voter1, operation)
+        .expect("Submitting operation should succeed")
+        .data;
+    assert_eq!(session.proposal_count(), 0);
+    assert_eq!(session.approve_operation(&voter1, op).err(), Some(VoteError::AlreadyApproved));
+    assert_eq!(
+        session.approve_operation(&*TestSession::PK_NO_TOKENS, op).err(),
+        Some(VoteError::NotCommitteeMember)
+    );
+
+    let receipt = session.approve_operation(&voter2, op).expect("Approving should succeed");
+    assert!(receipt.data);
+    assert_eq!(
+        events::<_, OperationApproved>(&receipt, OperationApproved::TOPIC),
+        vec![OperationApproved { operation_id: op, member: voter2, approvals: 2 }]
+    );
+    assert_eq!(
+        events::<_, OperationClosed>(&receipt, OperationClosed::EXECUTED_TOPIC),
+        vec![OperationClosed { operation_id: op }]
+    );
+    assert!(session.get_operation(op).is_none());
+    let proposal = session.get_proposal(0).expect("Proposal should exist");
+    assert_eq!(proposal.creator, committee_account);
+}
+
+#[test]
+fn test_committee_operation_can_be_withdrawn() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let voter2 = *TestSession::PK_VOTER2;
+
+    session
+        .set_committee(&admin, &[admin, voter1, voter2], 2)
+        .expect("Setting committee should succeed");
+    let op = session
+        .submit_operation(&admin, AdminOperation::ProposeAdmin(Account::External(voter1)))
+        .expect("Submitting operation should succeed")
+        .data;
+    let pending = session.get_operation(op).expect("Operation should be pending");
+    assert_eq!(pending.approvals, vec![admin]);
+
+    assert_eq!(session.cancel_operation(&voter1, op).err(), Some(VoteError::NotSubmitter));
+    session.cancel_operation(&admin, op).expect("Withdrawing should succeed");
+    assert_eq!(session.approve_operation(&voter2, op).err(), Some(VoteError::OperationNotFound));
+    assert_eq!(session.accept_admin(&voter1).err(), Some(VoteError::NoPendingTransfer));
+}
+
+#[test]
+fn test_callback_during_committee_operation_cannot_act_as_committee() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+    let caller = Account::Contract(CALLER_CONTRACT_ID);
+
+    // The caller mock tries to grant itself a role when asked for its supply
+    session.deploy_caller();
+    session.set_caller_callback("grant_role", &(Role::Proposer, caller));
+    let tokens = vec![TokenWeight { token: CALLER_CONTRACT_ID, multiplier: 1 }];
+    let params = NewProposal {
+        description: "Supply callback".to_string(),
+        start_height: 0,
+        end_height: 20,
+        rules: Some(VotingRules { quorum: Quorum::SupplyBps(1_000), ..VotingRules::DEFAULT }),
+        strategy: Some(VotingPowerStrategy {
+            source: PowerSource::MultiToken(tokens),
+            transform: PowerTransform::Linear,
+        }),
+        ..NewProposal::default()
+    };
+    let id = session
+        .add_proposal_with(&admin, params)
+        .expect("Adding proposal should succeed")
+        .data;
+    session
+        .set_committee(&admin, &[admin, voter1], 2)
+        .expect("Setting committee should succeed");
+
+    // Closing reads the supply while the committee operation runs
+    session.set_block_height(20);
+    let op = session
+        .submit_operation(&admin, AdminOperation::CloseProposal(id))
+        .expect("Submitting operation should succeed")
+        .data;
+    session.approve_operation(&voter1, op).expect("Approving should succeed");
+    assert_eq!(session.proposal_status(id), Some(ProposalStatus::Defeated));
+    assert!(!session.role_members(Role::Proposer).contains(&caller));
+}
+
+// ==================== Error Tests ====================
+
+#[test]
+fn test_rejected_calls_leave_state_unchanged() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+
+    assert_eq!(
+        session.add_proposal(&voter1, "Not allowed", 0, 20, None).err(),
+        Some(VoteError::MissingRole)
+    );
+    assert_eq!(session.proposal_count(), 0);
+
+    let id = session
+        .add_proposal(&admin, "Error test", 0, 20, None)
+        .expect("Adding proposal should succeed")
+        .data;
+    let error = session.vote(&*TestSession::PK_NO_TOKENS, id, true).err();
+    assert_eq!(error, Some(VoteError::NoVotingPower));
+    assert_eq!(error.map(|e| e.code()), Some(10));
+    assert!(session.get_ballot(&*TestSession::PK_NO_TOKENS, id).is_none());
+}
+
+// ==================== Event Tests ====================
+
+#[test]
+fn test_proposal_lifecycle_events() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+
+    let receipt = session
+        .add_proposal(&admin, "Event test", 0, 20, None)
+        .expect("Adding proposal should succeed");
+    let id = receipt.data;
+    assert_eq!(
+        events::<_, ProposalCreated>(&receipt, ProposalCreated::TOPIC),
+        vec![ProposalCreated {
+            proposal_id: id,
+            creator: Account::External(admin),
+            start_height: 0,
+            end_height: 20,
+            snapshot_height: 0,
+            option_count: 0,
+            category: String::new(),
+        }]
+    );
+
+    session.set_block_height(1);
+    let receipt = session.vote(&admin, id, true).expect("Voting should succeed");
+    assert_eq!(
+        events::<_, VoteCast>(&receipt, VoteCast::TOPIC),
+        vec![VoteCast {
+            proposal_id: id,
+            voter: Account::External(admin),
+            choice: Some(Choice::Standard(VoteOption::Yes)),
+            weight: 1000,
+        }]
+    );
+
+    let receipt = session.retract_vote(&admin, id).expect("Retracting vote should succeed");
+    let retracted = events::<_, VoteCast>(&receipt, VoteCast::RETRACTED_TOPIC);
+    assert_eq!(retracted.len(), 1);
+    assert_eq!(retracted[0].choice, None);
+    assert_eq!(retracted[0].weight, 1000);
+    session.vote(&admin, id, true).expect("Voting should succeed");
+
+    session.set_block_height(20);
+    let receipt = session.close_proposal(&admin, id).expect("Closing proposal should succeed");
+    assert_eq!(
+        events::<_, ProposalClosed>(&receipt, ProposalClosed::TOPIC),
+        vec![ProposalClosed {
+            proposal_id: id,
+            outcome: ProposalOutcome::Passed,
+            winning_option: None,
+        }]
+    );
+}
+
+#[test]
+fn test_admin_transfer_events() {
+    let mut session = TestSession::new();
+    let admin = *TestSession::PK_ADMIN;
+    let voter1 = *TestSession::PK_VOTER1;
+
+    let proposed = AdminProposed {
+        admin: Account::External(admin),
+        pending_admin: Account::External(voter1),
+    };
+    let receipt = session.propose_admin(&admin, &voter1).expect("Proposing admin should succeed");
+    assert_eq!(events::<_, AdminProposed>(&receipt, AdminProposed::TOPIC), vec![proposed]);
+
+    let receipt = session
+        .cancel_admin_proposal(&admin)
+        .expect("Cancelling admin transfer should succeed");
+    assert_eq!(
+        events::<_, AdminTransferCancelled>(&receipt, AdminTransferCancelled::TOPIC),
+        vec![AdminTransferCancelled {
+            admin: Account::External(admin),
+            pending_admin: Account::External(voter1),
+        }]
+    );
+
+    session.propose_admin(&admin, &voter1).expect("Proposing admin should succeed");
+    let receipt = session.accept_admin(&voter1).expect("Accepting admin should succeed");
+    assert_eq!(
+        events::<_, AdminAccepted>(&receipt, AdminAccepted::TOPIC),
+        vec![AdminAccepted {
+            previous_admin: Account::External(admin),
+            admin: Account::External(voter1),
+        }]
+    );
+
+    // The transfer is complete, so there is nothing left to accept
+    assert_eq!(session.accept_admin(&voter1).err(), Some(VoteError::NoPendingTransfer));
+}
+
+#[test]
+fn test_delegation_events() {
+    let mut session = TestSession::new();
+    let voter1 = *TestSession::PK_VOTER1;
+    let voter2 = *TestSession::PK_VOTER2;
+
+    let receipt = session.delegate(&voter2, &voter1).expect("Delegating should succeed");
+    assert_eq!(
+        events::<_, DelegationChanged>(&receipt, DelegationChanged::TOPIC),
+        vec![DelegationChanged {
+            delegator: Account::External(voter2),
+            scope: DelegationScope::Global,
+            delegate: Some(Account::External(voter1)),
+        }]
+    );
+
+    let receipt = session.undelegate(&voter2).expect("Undelegating should succeed");
+    let removed = events::<_, DelegationChanged>(&receipt, DelegationChanged::TOPIC);
+    assert_eq!(removed.len(), 1);
+    assert_eq!(removed[0].delegate, None);
+}